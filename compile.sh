@@ -45,15 +45,15 @@ for opt in "${OPT_LEVELS[@]}"; do
   
   echo "Compiling Rust VANILLA (O$opt)"
   RUSTC="$VANILLA_RUSTC" RUSTFLAGS="-C opt-level=$opt" \
-    cargo build --release --target-dir "target_vanilla_O$opt"
-  cp "target_vanilla_O$opt/release/skip_list_rust" "binaries/skip_list_rust_vanilla_O$opt"
+    cargo build --release --features benchmark --bin benchmark --target-dir "target_vanilla_O$opt"
+  cp "target_vanilla_O$opt/release/benchmark" "binaries/skip_list_rust_vanilla_O$opt"
   echo "✓ Vanilla Rust: binaries/skip_list_rust_vanilla_O$opt"
   echo
   
   echo "Compiling Rust CUSTOM (O$opt)"
   RUSTC="$CUSTOM_RUSTC" RUSTFLAGS="-C opt-level=$opt" \
-    cargo build --release --target-dir "target_custom_O$opt"
-  cp "target_custom_O$opt/release/skip_list_rust" "binaries/skip_list_rust_custom_O$opt"
+    cargo build --release --features benchmark --bin benchmark --target-dir "target_custom_O$opt"
+  cp "target_custom_O$opt/release/benchmark" "binaries/skip_list_rust_custom_O$opt"
   echo "✓ Custom Rust: binaries/skip_list_rust_custom_O$opt"
   echo
 done
@@ -1,7 +1,4 @@
-mod skiplist;
-
-use skiplist::SkipList;
-use crate::skiplist::get_max_level;
+use skip_list_rust::{get_max_level, SkipList};
 use std::cmp::Ordering;
 
 // fn main() {
@@ -12,7 +9,7 @@ use std::cmp::Ordering;
 //         "z", "g", "o", "s", "h", "v", "i", "j", "p", "k", "r", "x", "l",
 //     ];
 
-//     let max_level = get_max_level(data.len(), P);
+//     let max_level = get_max_level(data.len(), P).unwrap();
 //     println!("max level: {}", max_level);
 
 //     let mut skip_list = SkipList::<String, ()>::new(max_level, 0.5, Ord::cmp);
@@ -32,7 +29,7 @@ fn int_comparator(a: &i32, b: &i32) -> Ordering {
 
 fn main() {
     // Create a skip list similar to the C version
-    let max_level = get_max_level(100, 0.5);
+    let max_level = get_max_level(100, 0.5).unwrap();
     println!("max level: {}", max_level);
     
     let mut skip_list = SkipList::new(max_level, 0.5, int_comparator);
@@ -74,7 +71,7 @@ fn main() {
     
     // Display the skip list structure
     println!("\n=== Skip List Structure ===");
-    skip_list.display_list(Some(|key: &i32, data: &String| {
+    skip_list.display_list(Some(|key: &i32, _data: &String| {
         print!("{:>6}", key);
     }));
     
@@ -91,7 +88,7 @@ fn main() {
     
     // Display after removals
     println!("\n=== Skip List After Removals ===");
-    skip_list.display_list(Some(|key: &i32, data: &String| {
+    skip_list.display_list(Some(|key: &i32, _data: &String| {
         print!("{:>6}", key);
     }));
     
@@ -99,7 +96,7 @@ fn main() {
     println!("\n=== Testing Edge Cases ===");
     
     // Test empty operations - need to specify the data type explicitly
-    let mut empty_list: SkipList<i32, String> = SkipList::new(4, 0.5, int_comparator);
+    let mut empty_list: SkipList<i32, String, _> = SkipList::new(4, 0.5, int_comparator);
     println!("Empty list search for 5: {:?}", empty_list.search(&5));
     println!("Empty list remove 5: {:?}", empty_list.remove(&5));
     println!("Empty list key_at(0): {:?}", empty_list.key_at(0));
@@ -111,75 +108,3 @@ fn main() {
     println!("NOT Out of bounds access key_at({}): {:?}", skip_list.len() - 1, skip_list.key_at(skip_list.len() - 1));
     println!("NOT Out of bounds access data_at({}): {:?}", skip_list.len() - 1, skip_list.data_at(skip_list.len() - 1));
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    
-    #[test]
-    fn test_basic_operations() {
-        let mut skip_list = SkipList::new(16, 0.5, int_comparator);
-        
-        // Test insertion and search
-        assert_eq!(skip_list.insert(5, "five".to_string()), None);
-        assert_eq!(skip_list.insert(3, "three".to_string()), None);
-        assert_eq!(skip_list.insert(7, "seven".to_string()), None);
-        
-        assert_eq!(skip_list.search(&5), Some("five".to_string()));
-        assert_eq!(skip_list.search(&3), Some("three".to_string()));
-        assert_eq!(skip_list.search(&7), Some("seven".to_string()));
-        assert_eq!(skip_list.search(&1), None);
-        
-        assert_eq!(skip_list.len(), 3);
-    }
-    
-    #[test]
-    fn test_update() {
-        let mut skip_list = SkipList::new(16, 0.5, int_comparator);
-        
-        skip_list.insert(5, "five".to_string());
-        let old = skip_list.insert(5, "FIVE".to_string());
-        
-        assert_eq!(old, Some("five".to_string()));
-        assert_eq!(skip_list.search(&5), Some("FIVE".to_string()));
-        assert_eq!(skip_list.len(), 1);
-    }
-    
-    #[test]
-    fn test_removal() {
-        let mut skip_list = SkipList::new(16, 0.5, int_comparator);
-        
-        for i in [1, 3, 5, 7, 9] {
-            skip_list.insert(i, format!("val_{}", i));
-        }
-        
-        assert_eq!(skip_list.remove(&5), Some("val_5".to_string()));
-        assert_eq!(skip_list.remove(&5), None);
-        assert_eq!(skip_list.search(&5), None);
-        assert_eq!(skip_list.len(), 4);
-        
-        // Test removing first and last elements
-        assert_eq!(skip_list.remove(&1), Some("val_1".to_string()));
-        assert_eq!(skip_list.remove(&9), Some("val_9".to_string()));
-        assert_eq!(skip_list.len(), 2);
-    }
-    
-    #[test]
-    fn test_indexed_access() {
-        let mut skip_list = SkipList::new(16, 0.5, int_comparator);
-        
-        let keys = [3, 1, 4, 1, 5, 9, 2, 6];
-        for (i, &key) in keys.iter().enumerate() {
-            skip_list.insert(key, i);
-        }
-        
-        // Keys should be sorted: [1, 2, 3, 4, 5, 6, 9] (duplicate 1 updated)
-        let expected_keys = [1, 2, 3, 4, 5, 6, 9];
-        
-        for (i, &expected_key) in expected_keys.iter().enumerate() {
-            assert_eq!(skip_list.key_at(i), Some(expected_key));
-        }
-        
-        assert_eq!(skip_list.key_at(expected_keys.len()), None);
-    }
-}
@@ -1,7 +1,4 @@
-mod skiplist;
-
-use skiplist::SkipList;
-use crate::skiplist::get_max_level;
+use skip_list_rust::{get_max_level, SkipList};
 use std::cmp::Ordering;
 
 fn int_comparator(a: &i32, b: &i32) -> Ordering {
@@ -10,7 +7,7 @@ fn int_comparator(a: &i32, b: &i32) -> Ordering {
 
 fn main() {
     // Create a skip list similar to the C version
-    let max_level = get_max_level(1000, 0.5);
+    let max_level = get_max_level(1000, 0.5).unwrap();
     println!("max level: {}", max_level);
     
     let mut skip_list = SkipList::new(max_level, 0.5, int_comparator);
@@ -1,6 +1,5 @@
-mod skiplist;
-use skiplist::SkipList;
-use crate::skiplist::get_max_level;
+use skip_list_rust::{get_max_level, CRand, SkipList};
+#[path = "../data.rs"]
 mod data;
 use data::*;
 use std::cmp::Ordering;
@@ -9,8 +8,15 @@ use std::time::Instant;
 fn int_comparator(a: &i32, b: &i32) -> Ordering { a.cmp(b) }
 
 fn main() {
-    let max_level = get_max_level(N, 0.5);
-    let mut sl = SkipList::new(max_level, 0.5, int_comparator);
+    let max_level = get_max_level(N, 0.5).unwrap();
+    // Levels come from the C library's rand(), like the C benchmark
+    let mut sl = SkipList::builder()
+        .comparator(int_comparator)
+        .max_level(max_level)
+        .probability(0.5)
+        .level_generator(CRand::new(42))
+        .build()
+        .expect("benchmark parameters are valid");
     
     println!("Starting benchmark with N={}", N);
 
@@ -33,7 +39,7 @@ fn main() {
     let mut remove_misses = 0;
     
     for i in 0..REMOVES {
-        let key = if REMOVE_IS_HIT[i] == true {
+        let key = if REMOVE_IS_HIT[i] {
             // Try to remove an existing key
             INSERT_KEYS[REMOVE_INDICES[i]]
         } else {
@@ -53,7 +59,7 @@ fn main() {
     let size_after_remove = sl.len();
     
     for i in 0..SEARCHES {
-        let key = if SEARCH_IS_HIT[i] == true && size_after_remove > 0 {
+        let key = if SEARCH_IS_HIT[i] && size_after_remove > 0 {
             // Try to search for an existing key (should find it and return Some(value))
             INSERT_KEYS[SEARCH_INDICES[i]]
         } else {
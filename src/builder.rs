@@ -0,0 +1,147 @@
+use std::marker::PhantomData;
+
+use crate::comparator::{Comparator, OrdComparator};
+use crate::error::ConfigError;
+use crate::level::{LevelGenerator, XorShift64Star};
+use crate::skiplist::{SkipList, DEFAULT_SEED};
+
+/// Configures a `SkipList` before creating it, see `SkipList::builder`.
+pub struct SkipListBuilder<K, D, C = OrdComparator, G = XorShift64Star> {
+    max_level: u16,
+    p: f32,
+    growth: Growth,
+    comparator: C,
+    generator: G,
+    _entry: PhantomData<fn() -> (K, D)>,
+}
+
+/// Levels used when the builder is not given `max_level`, enough for
+/// about 2^32 entries at `p = 0.5`.
+pub const DEFAULT_MAX_LEVEL: u16 = 32;
+
+/// Probability used when the builder is not given `probability`.
+pub const DEFAULT_PROBABILITY: f32 = 0.5;
+
+/// Fewest levels a list can be built with. As in the C version towers stop
+/// one level short of `max_level`, so with fewer every tower is flat and the
+/// list is a linked list.
+pub const MIN_MAX_LEVEL: u16 = 3;
+
+/// Checks `p` is strictly between 0 and 1.
+pub(crate) fn check_probability(p: f32) -> Result<(), ConfigError> {
+    if p.is_nan() || p <= 0.0 || p >= 1.0 {
+        return Err(ConfigError::InvalidProbability { p });
+    }
+    Ok(())
+}
+
+/// Checks the parameters the towers depend on, for the builder and `SkipList::new`.
+pub(crate) fn check_config(max_level: u16, p: f32) -> Result<(), ConfigError> {
+    check_probability(p)?;
+    if max_level < MIN_MAX_LEVEL {
+        return Err(ConfigError::InvalidMaxLevel { max_level });
+    }
+    Ok(())
+}
+
+/// How a list's `max_level` follows its length, see `SkipListBuilder::growth`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Growth {
+    /// `max_level` stays as configured. Past about `(1/p)^(max_level - 1)`
+    /// entries the towers stop getting taller and searches degrade towards
+    /// `O(n)`.
+    #[default]
+    Fixed,
+    /// The head gains a level whenever the length passes
+    /// `(1/p)^(max_level - 1)`, so towers can always reach the
+    /// `log_{1/p}(len)` levels the length calls for.
+    Grow,
+    /// Like `Grow`, and the head also gives up its top level while the length
+    /// is below `(1/p)^(max_level - 3)` and no tower reaches that level.
+    GrowAndShrink,
+}
+
+impl<K, D> SkipList<K, D> {
+    /// Starts configuring a list. Unless overridden, it is ordered by
+    /// `OrdComparator` and uses `DEFAULT_MAX_LEVEL`, `DEFAULT_PROBABILITY`
+    /// and an `XorShift64Star` seeded with 42.
+    pub fn builder() -> SkipListBuilder<K, D> {
+        SkipListBuilder {
+            max_level: DEFAULT_MAX_LEVEL,
+            p: DEFAULT_PROBABILITY,
+            growth: Growth::Fixed,
+            comparator: OrdComparator,
+            generator: XorShift64Star::new(DEFAULT_SEED),
+            _entry: PhantomData,
+        }
+    }
+}
+
+impl<K, D, C, G> SkipListBuilder<K, D, C, G> {
+    /// Levels a tower can span, at least `MIN_MAX_LEVEL`.
+    pub fn max_level(mut self, max_level: u16) -> Self {
+        self.max_level = max_level;
+        self
+    }
+
+    /// Chance of a tower reaching each next level, strictly between 0 and 1.
+    pub fn probability(mut self, p: f32) -> Self {
+        self.p = p;
+        self
+    }
+
+    /// Lets `max_level` follow the length of the list instead of staying
+    /// fixed, the configured value being where it starts.
+    pub fn growth(mut self, growth: Growth) -> Self {
+        self.growth = growth;
+        self
+    }
+
+    /// Replaces the order of the keys, e.g. with a closure.
+    pub fn comparator<C2: Comparator<K>>(self, comparator: C2) -> SkipListBuilder<K, D, C2, G> {
+        SkipListBuilder {
+            max_level: self.max_level,
+            p: self.p,
+            growth: self.growth,
+            comparator,
+            generator: self.generator,
+            _entry: PhantomData,
+        }
+    }
+
+    /// Replaces the level generator, e.g. with `SplitMix64` or, for parity
+    /// runs against the C implementation, `CRand`.
+    pub fn level_generator<G2: LevelGenerator>(self, generator: G2) -> SkipListBuilder<K, D, C, G2> {
+        SkipListBuilder {
+            max_level: self.max_level,
+            p: self.p,
+            growth: self.growth,
+            comparator: self.comparator,
+            generator,
+            _entry: PhantomData,
+        }
+    }
+}
+
+impl<K, D, C, G: LevelGenerator> SkipListBuilder<K, D, C, G> {
+    /// Reseeds the level generator.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.generator = G::from_seed(seed);
+        self
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipListBuilder<K, D, C, G> {
+    /// Creates the empty list, or reports the first parameter that would
+    /// make it degenerate.
+    pub fn build(self) -> Result<SkipList<K, D, C, G>, ConfigError> {
+        check_config(self.max_level, self.p)?;
+        Ok(SkipList::with_generator(
+            self.max_level,
+            self.p,
+            self.growth,
+            self.comparator,
+            self.generator,
+        ))
+    }
+}
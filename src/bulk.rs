@@ -0,0 +1,56 @@
+use std::cmp::Ordering;
+
+use crate::builder::SkipListBuilder;
+use crate::comparator::Comparator;
+use crate::error::{BuildError, OrderError};
+use crate::level::LevelGenerator;
+use crate::skiplist::SkipList;
+
+impl<K, D, C: Comparator<K> + Default, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Builds a list from entries in strictly increasing key order in a single
+    /// `O(n)` pass, without searching for each insertion point.
+    pub fn from_sorted_iter<I: IntoIterator<Item = (K, D)>>(iter: I) -> Result<Self, OrderError> {
+        let mut list = SkipList::default();
+        list.fill_sorted(iter)?;
+        Ok(list)
+    }
+
+    /// Like `from_sorted_iter`, allocating the node arena once up front.
+    pub fn from_sorted_vec(entries: Vec<(K, D)>) -> Result<Self, OrderError> {
+        Self::from_sorted_iter(entries)
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipListBuilder<K, D, C, G> {
+    /// Like `SkipList::from_sorted_iter`, for the configured list.
+    pub fn build_from_sorted<I: IntoIterator<Item = (K, D)>>(
+        self,
+        iter: I,
+    ) -> Result<SkipList<K, D, C, G>, BuildError> {
+        let mut list = self.build()?;
+        list.fill_sorted(iter)?;
+        Ok(list)
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Appends the entries of `iter` to an empty list, checking each key
+    /// against the one before it.
+    fn fill_sorted<I: IntoIterator<Item = (K, D)>>(&mut self, iter: I) -> Result<(), OrderError> {
+        debug_assert!(self.is_empty());
+        let iter = iter.into_iter();
+        self.nodes.reserve(iter.size_hint().0);
+
+        let (mut tail, mut position) = self.find_tail();
+        for (index, (key, data)) in iter.enumerate() {
+            // The head sorts before every key, so the first entry always passes
+            match self.compare(tail[0], &key) {
+                Ordering::Less => {}
+                Ordering::Equal => return Err(OrderError::Duplicate { index }),
+                Ordering::Greater => return Err(OrderError::OutOfOrder { index }),
+            }
+            self.push_back_unchecked(&mut tail, &mut position, key, data);
+        }
+        Ok(())
+    }
+}
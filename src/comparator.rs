@@ -0,0 +1,27 @@
+use std::cmp::Ordering;
+
+/// Defines the order of the keys in a `SkipList`.
+///
+/// Implemented for every `Fn(&K, &K) -> Ordering`, so plain functions and
+/// closures capturing state (a collation table, a reverse flag) both work.
+/// Zero-sized comparators such as `OrdComparator` or a function item cost
+/// nothing to store and are inlined into the search loops.
+pub trait Comparator<K: ?Sized> {
+    fn compare(&self, a: &K, b: &K) -> Ordering;
+}
+
+impl<K: ?Sized, F: Fn(&K, &K) -> Ordering> Comparator<K> for F {
+    fn compare(&self, a: &K, b: &K) -> Ordering {
+        self(a, b)
+    }
+}
+
+/// Orders keys by their `Ord` implementation. This is the default comparator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrdComparator;
+
+impl<K: Ord + ?Sized> Comparator<K> for OrdComparator {
+    fn compare(&self, a: &K, b: &K) -> Ordering {
+        a.cmp(b)
+    }
+}
@@ -0,0 +1,141 @@
+use std::borrow::Borrow;
+use std::iter::FusedIterator;
+use std::ops::RangeBounds;
+
+use crate::comparator::Comparator;
+use crate::level::LevelGenerator;
+use crate::skiplist::{NodeId, SkipList, HEAD};
+
+/// Owning iterator over the entries removed by `SkipList::drain_range`.
+///
+/// The entries are unlinked up front; dropping the iterator frees the ones
+/// not yet yielded.
+pub struct DrainRange<'a, K, D, C, G> {
+    list: &'a mut SkipList<K, D, C, G>,
+    front: NodeId,
+    len: usize,
+}
+
+/// Iterator removing the entries matching a predicate, created by
+/// `SkipList::extract_if`.
+///
+/// The list stays valid after every step. Entries not visited when the
+/// iterator is dropped are kept.
+pub struct ExtractIf<'a, K, D, C, G, F> {
+    list: &'a mut SkipList<K, D, C, G>,
+    // Last node visited on every level, the predecessors of the next candidate
+    update: Vec<NodeId>,
+    remaining: usize,
+    pred: F,
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Keeps only the entries for which `f` returns `true`, in one pass over
+    /// level 0.
+    pub fn retain<F: FnMut(&K, &mut D) -> bool>(&mut self, mut f: F) {
+        self.extract_if(|key, data| !f(key, data)).for_each(drop);
+    }
+
+    /// Removes the entries whose keys fall in `range` in `O(log n + k)` by
+    /// relinking every level past them at once, and returns how many there were.
+    pub fn remove_range<Q: ?Sized, R: RangeBounds<Q>>(&mut self, range: R) -> usize
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        // Dropping the iterator frees every drained entry
+        self.drain_range(range).len()
+    }
+
+    /// Like `remove_range`, but yields the removed entries in key order.
+    pub fn drain_range<Q: ?Sized, R: RangeBounds<Q>>(
+        &mut self,
+        range: R,
+    ) -> DrainRange<'_, K, D, C, G>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (start, len) = self.index_span(&range);
+        let front = self.detach_range(start, len).unwrap_or(HEAD);
+        DrainRange {
+            list: self,
+            front,
+            len,
+        }
+    }
+
+    /// Walks the entries in key order, removing and yielding those for which
+    /// `pred` returns `true`.
+    pub fn extract_if<F>(&mut self, pred: F) -> ExtractIf<'_, K, D, C, G, F>
+    where
+        F: FnMut(&K, &mut D) -> bool,
+    {
+        ExtractIf {
+            update: vec![HEAD; self.max_level()],
+            remaining: self.len(),
+            list: self,
+            pred,
+        }
+    }
+}
+
+impl<K, D, C, G> Iterator for DrainRange<'_, K, D, C, G> {
+    type Item = (K, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let id = self.front;
+        self.front = self.list.node(id).next().unwrap_or(HEAD);
+        Some(self.list.release(id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, D, C, G> ExactSizeIterator for DrainRange<'_, K, D, C, G> {}
+impl<K, D, C, G> FusedIterator for DrainRange<'_, K, D, C, G> {}
+
+impl<K, D, C, G> Drop for DrainRange<'_, K, D, C, G> {
+    fn drop(&mut self) {
+        self.for_each(drop);
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator, F: FnMut(&K, &mut D) -> bool> Iterator
+    for ExtractIf<'_, K, D, C, G, F>
+{
+    type Item = (K, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.remaining > 0 {
+            self.remaining -= 1;
+            let id = self.list.node(self.update[0]).next()?;
+            let node = self.list.node_mut(id);
+            let remove = match (node.key.as_ref(), node.data.as_mut()) {
+                (Some(key), Some(data)) => (self.pred)(key, data),
+                _ => unreachable!("linked nodes hold an entry"),
+            };
+            if remove {
+                return Some(self.list.unlink(&self.update, id));
+            }
+            let height = self.list.node(id).height();
+            self.update[..height].fill(id);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator, F: FnMut(&K, &mut D) -> bool> FusedIterator
+    for ExtractIf<'_, K, D, C, G, F>
+{
+}
@@ -0,0 +1,160 @@
+use crate::comparator::Comparator;
+use crate::level::LevelGenerator;
+use crate::skiplist::{NodeId, SkipList};
+
+/// A view into a single entry of a `SkipList`, created by `SkipList::entry`.
+///
+/// Both variants keep the predecessors found while descending to the key, so
+/// inserting into a vacant entry or removing an occupied one does not search
+/// the list a second time.
+pub enum Entry<'a, K, D, C, G> {
+    Vacant(VacantEntry<'a, K, D, C, G>),
+    Occupied(OccupiedEntry<'a, K, D, C, G>),
+}
+
+/// An entry whose key is not in the list.
+pub struct VacantEntry<'a, K, D, C, G> {
+    list: &'a mut SkipList<K, D, C, G>,
+    key: K,
+    update: Vec<NodeId>,
+    update_width: Vec<usize>,
+}
+
+/// An entry whose key is in the list.
+pub struct OccupiedEntry<'a, K, D, C, G> {
+    list: &'a mut SkipList<K, D, C, G>,
+    node: NodeId,
+    update: Vec<NodeId>,
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Gets the entry for `key` with a single descent, for in-place updates.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, D, C, G> {
+        let (update, update_width) = self.find_update(&key);
+        match self.node(update[0]).next() {
+            Some(node) if self.compare(node, &key) == std::cmp::Ordering::Equal => {
+                Entry::Occupied(OccupiedEntry {
+                    list: self,
+                    node,
+                    update,
+                })
+            }
+            _ => Entry::Vacant(VacantEntry {
+                list: self,
+                key,
+                update,
+                update_width,
+            }),
+        }
+    }
+}
+
+impl<'a, K, D, C: Comparator<K>, G: LevelGenerator> Entry<'a, K, D, C, G> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Vacant(entry) => entry.key(),
+            Entry::Occupied(entry) => entry.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns the data.
+    pub fn or_insert(self, default: D) -> &'a mut D {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Like `or_insert`, but only calls `default` if the entry is vacant.
+    pub fn or_insert_with<F: FnOnce() -> D>(self, default: F) -> &'a mut D {
+        match self {
+            Entry::Vacant(entry) => entry.insert(default()),
+            Entry::Occupied(entry) => entry.into_mut(),
+        }
+    }
+
+    /// Runs `f` on the data if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut D)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, D: Default, C: Comparator<K>, G: LevelGenerator> Entry<'a, K, D, C, G> {
+    pub fn or_default(self) -> &'a mut D {
+        self.or_insert_with(D::default)
+    }
+}
+
+impl<'a, K, D, C: Comparator<K>, G: LevelGenerator> VacantEntry<'a, K, D, C, G> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Links a new node for the key holding `data`, and returns the data.
+    pub fn insert(mut self, data: D) -> &'a mut D {
+        let node = self
+            .list
+            .link_new(&mut self.update, &mut self.update_width, self.key, data);
+        self.list
+            .node_mut(node)
+            .data
+            .as_mut()
+            .expect("linked nodes hold an entry")
+    }
+}
+
+impl<'a, K, D, C: Comparator<K>, G: LevelGenerator> OccupiedEntry<'a, K, D, C, G> {
+    pub fn key(&self) -> &K {
+        self.list
+            .node(self.node)
+            .key
+            .as_ref()
+            .expect("linked nodes hold an entry")
+    }
+
+    pub fn get(&self) -> &D {
+        self.list
+            .node(self.node)
+            .data
+            .as_ref()
+            .expect("linked nodes hold an entry")
+    }
+
+    pub fn get_mut(&mut self) -> &mut D {
+        self.list
+            .node_mut(self.node)
+            .data
+            .as_mut()
+            .expect("linked nodes hold an entry")
+    }
+
+    /// Converts the entry into a borrow of its data that lives as long as the list borrow.
+    pub fn into_mut(self) -> &'a mut D {
+        self.list
+            .node_mut(self.node)
+            .data
+            .as_mut()
+            .expect("linked nodes hold an entry")
+    }
+
+    /// Replaces the data, returning the previous data.
+    pub fn insert(&mut self, data: D) -> D {
+        std::mem::replace(self.get_mut(), data)
+    }
+
+    pub fn remove(self) -> D {
+        self.remove_entry().1
+    }
+
+    /// Unlinks the entry, returning its key and data.
+    pub fn remove_entry(self) -> (K, D) {
+        self.list.unlink(&self.update, self.node)
+    }
+}
@@ -0,0 +1,179 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::builder::MIN_MAX_LEVEL;
+
+/// Input to `SkipList::from_sorted_iter` or `from_sorted_vec` that is not
+/// strictly increasing under the list's comparator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OrderError {
+    /// The key at `index` is equal to the key before it.
+    Duplicate { index: usize },
+    /// The key at `index` sorts before the key before it.
+    OutOfOrder { index: usize },
+}
+
+impl fmt::Display for OrderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OrderError::Duplicate { index } => {
+                write!(f, "key at index {} duplicates the previous key", index)
+            }
+            OrderError::OutOfOrder { index } => {
+                write!(f, "key at index {} sorts before the previous key", index)
+            }
+        }
+    }
+}
+
+impl Error for OrderError {}
+
+/// A parameter rejected by `SkipListBuilder::build`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConfigError {
+    /// `p` is not strictly between 0 and 1. At 0 every tower is flat, at 1
+    /// every tower is as tall as `max_level` allows.
+    InvalidProbability { p: f32 },
+    /// `max_level` is below `MIN_MAX_LEVEL`.
+    InvalidMaxLevel { max_level: u16 },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::InvalidProbability { p } => {
+                write!(f, "probability must be strictly between 0 and 1, got {}", p)
+            }
+            ConfigError::InvalidMaxLevel { max_level } => write!(
+                f,
+                "max_level must be at least {}, got {}",
+                MIN_MAX_LEVEL, max_level
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+/// Failure of `SkipListBuilder::build_from_sorted`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuildError {
+    Config(ConfigError),
+    Order(OrderError),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::Config(error) => error.fmt(f),
+            BuildError::Order(error) => error.fmt(f),
+        }
+    }
+}
+
+impl Error for BuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            BuildError::Config(error) => Some(error),
+            BuildError::Order(error) => Some(error),
+        }
+    }
+}
+
+impl From<ConfigError> for BuildError {
+    fn from(error: ConfigError) -> Self {
+        BuildError::Config(error)
+    }
+}
+
+impl From<OrderError> for BuildError {
+    fn from(error: OrderError) -> Self {
+        BuildError::Order(error)
+    }
+}
+
+/// A broken structural invariant found by `SkipList::validate`. Indexes are
+/// the 0-based positions of nodes along level 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Violation {
+    /// The key at `index` does not sort strictly after the key before it.
+    OutOfOrder { index: usize },
+    /// The backward link of the node at `index` does not lead to the node
+    /// before it, or the head's does not lead to the last node.
+    BrokenBackward { index: usize },
+    /// A link on `level` leaving the node at `from` (the head when `None`)
+    /// spans `found` level-0 hops instead of `expected`.
+    WrongWidth {
+        level: usize,
+        from: Option<usize>,
+        expected: usize,
+        found: usize,
+    },
+    /// A link on `level` leads to a node that is not on level 0, or is not
+    /// tall enough to be linked on `level`.
+    BadTarget { level: usize, from: Option<usize> },
+    /// The list records `recorded` entries but level 0 holds `counted`.
+    WrongLength { recorded: usize, counted: usize },
+    /// The list records `recorded` levels but `expected` are in use.
+    WrongLevel { recorded: usize, expected: usize },
+    /// Arena slots that are neither linked nor on the free list.
+    LeakedSlots { count: usize },
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let node = |from: &Option<usize>| match from {
+            Some(index) => format!("node {}", index),
+            None => "the head".to_string(),
+        };
+        match self {
+            Violation::OutOfOrder { index } => {
+                write!(f, "key at index {} does not sort after the previous key", index)
+            }
+            Violation::BrokenBackward { index } => {
+                write!(f, "backward link of node {} is wrong", index)
+            }
+            Violation::WrongWidth { level, from, expected, found } => write!(
+                f,
+                "level {} link from {} has width {}, expected {}",
+                level,
+                node(from),
+                found,
+                expected
+            ),
+            Violation::BadTarget { level, from } => write!(
+                f,
+                "level {} link from {} leads to a node not linked there",
+                level,
+                node(from)
+            ),
+            Violation::WrongLength { recorded, counted } => {
+                write!(f, "length is {} but level 0 holds {} entries", recorded, counted)
+            }
+            Violation::WrongLevel { recorded, expected } => {
+                write!(f, "level is {} but {} levels are in use", recorded, expected)
+            }
+            Violation::LeakedSlots { count } => {
+                write!(f, "{} arena slots are neither linked nor free", count)
+            }
+        }
+    }
+}
+
+/// Every invariant `SkipList::validate` found broken.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pub violations: Vec<Violation>,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "skip list has {} broken invariants", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n  {}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for ValidationError {}
@@ -0,0 +1,151 @@
+use std::fmt::Write;
+
+use crate::skiplist::{NodeId, SkipList, HEAD};
+
+impl<K, D, C, G> SkipList<K, D, C, G> {
+    /// Index of every linked node along level 0, by arena slot.
+    fn numbering(&self) -> Vec<usize> {
+        let mut index = vec![0; self.nodes.len()];
+        let mut current = HEAD;
+        let mut next_index = 0;
+        while let Some(next) = self.node(current).next() {
+            index[next as usize] = next_index;
+            next_index += 1;
+            current = next;
+        }
+        index
+    }
+
+    /// The linked nodes in key order with their labels.
+    fn labelled<F: Fn(&K, &D) -> String>(&self, label: F) -> Vec<(NodeId, String)> {
+        let mut nodes = Vec::with_capacity(self.width);
+        let mut current = HEAD;
+        while let Some(next) = self.node(current).next() {
+            let node = self.node(next);
+            if let (Some(key), Some(data)) = (node.key.as_ref(), node.data.as_ref()) {
+                nodes.push((next, label(key, data)));
+            }
+            current = next;
+        }
+        nodes
+    }
+
+    /// Renders the towers as a Graphviz graph: one record per node with a
+    /// port per level, and an edge labelled with its width for every link.
+    /// `label` formats the text shown under each tower.
+    pub fn to_dot<F: Fn(&K, &D) -> String>(&self, label: F) -> String {
+        let index = self.numbering();
+        let name = |id: NodeId| match id {
+            HEAD => "head".to_string(),
+            _ => format!("n{}", index[id as usize]),
+        };
+        let ports = |height: usize| {
+            (0..height).rev().map(|level| format!("<l{}> {}|", level, level)).collect::<String>()
+        };
+
+        let mut out = String::new();
+        out.push_str("digraph skiplist {\n    rankdir=LR;\n    node [shape=record];\n");
+        let _ = writeln!(out, "    head [label=\"{}head\"];", ports(self.level()));
+        let nodes = self.labelled(label);
+        for (id, text) in &nodes {
+            let height = self.node(*id).height();
+            let record = format!("{}{}", ports(height), escape_record(text));
+            let _ = writeln!(out, "    {} [label=\"{}\"];", name(*id), record);
+        }
+        for id in std::iter::once(HEAD).chain(nodes.iter().map(|(id, _)| *id)) {
+            let height = if id == HEAD { self.level() } else { self.node(id).height() };
+            for level in 0..height {
+                let link = self.node(id).link(level);
+                if let Some(target) = link.node {
+                    let _ = writeln!(
+                        out,
+                        "    {}:l{} -> {}:l{} [label=\"{}\"];",
+                        name(id),
+                        level,
+                        name(target),
+                        level,
+                        link.width
+                    );
+                }
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Dumps the structure as JSON: the length, the level in use, the head's
+    /// links and every node's label and links in key order. A link records
+    /// its width and the index of the node it leads to, or `null`.
+    pub fn to_json<F: Fn(&K, &D) -> String>(&self, label: F) -> String {
+        let index = self.numbering();
+        let links = |id: NodeId, height: usize| {
+            let links: Vec<String> = (0..height)
+                .map(|level| {
+                    let link = self.node(id).link(level);
+                    let to = match link.node {
+                        Some(target) => index[target as usize].to_string(),
+                        None => "null".to_string(),
+                    };
+                    format!("{{\"width\":{},\"to\":{}}}", link.width, to)
+                })
+                .collect();
+            links.join(",")
+        };
+
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "{{\"len\":{},\"level\":{},\"head\":[{}],\"nodes\":[",
+            self.width,
+            self.level(),
+            links(HEAD, self.level())
+        );
+        for (i, (id, text)) in self.labelled(label).into_iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "{{\"label\":\"{}\",\"links\":[{}]}}",
+                escape_json(&text),
+                links(id, self.node(id).height())
+            );
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+/// Escapes the characters that structure a Graphviz record label.
+fn escape_record(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '{' | '}' | '|' | '<' | '>' | '"' | '\\' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn escape_json(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,344 @@
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+
+use crate::skiplist::{NodeId, SkipList, SkipNode, HEAD};
+
+/// Iterator over `(&K, &D)` in key order, created by `SkipList::iter`.
+pub struct Iter<'a, K, D> {
+    nodes: &'a [SkipNode<K, D>],
+    front: NodeId,
+    back: NodeId,
+    len: usize,
+}
+
+/// Iterator over `(&K, &mut D)` in key order, created by `SkipList::iter_mut`.
+pub struct IterMut<'a, K, D> {
+    // Raw so that entries of different nodes can be handed out at once. Every
+    // node between `front` and `back` is yielded at most once.
+    nodes: *mut SkipNode<K, D>,
+    front: NodeId,
+    back: NodeId,
+    len: usize,
+    _marker: PhantomData<&'a mut SkipNode<K, D>>,
+}
+
+/// Owning iterator over `(K, D)` in key order, created by `SkipList::into_iter`.
+pub struct IntoIter<K, D> {
+    nodes: Vec<SkipNode<K, D>>,
+    front: NodeId,
+    back: NodeId,
+    len: usize,
+}
+
+/// Iterator over the keys of a `SkipList`, created by `SkipList::keys`.
+pub struct Keys<'a, K, D> {
+    inner: Iter<'a, K, D>,
+}
+
+/// Iterator over the data of a `SkipList`, created by `SkipList::values`.
+pub struct Values<'a, K, D> {
+    inner: Iter<'a, K, D>,
+}
+
+/// Mutable iterator over the data of a `SkipList`, created by `SkipList::values_mut`.
+pub struct ValuesMut<'a, K, D> {
+    inner: IterMut<'a, K, D>,
+}
+
+/// Reads the key and data of a node that holds an entry.
+fn entry<K, D>(node: &SkipNode<K, D>) -> (&K, &D) {
+    match (node.key.as_ref(), node.data.as_ref()) {
+        (Some(key), Some(data)) => (key, data),
+        _ => unreachable!("linked nodes hold an entry"),
+    }
+}
+
+impl<K, D, C, G> SkipList<K, D, C, G> {
+    /// First and last node of the list, `HEAD` for both when it is empty.
+    fn ends(&self) -> (NodeId, NodeId) {
+        let head = &self.nodes[HEAD as usize];
+        (head.next().unwrap_or(HEAD), head.backward)
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, D> {
+        let (front, back) = self.ends();
+        Iter::new(&self.nodes, front, back, self.width)
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, D> {
+        let (front, back) = self.ends();
+        IterMut::new(&mut self.nodes, front, back, self.width)
+    }
+
+    pub fn keys(&self) -> Keys<'_, K, D> {
+        Keys { inner: self.iter() }
+    }
+
+    pub fn values(&self) -> Values<'_, K, D> {
+        Values { inner: self.iter() }
+    }
+
+    pub fn values_mut(&mut self) -> ValuesMut<'_, K, D> {
+        ValuesMut {
+            inner: self.iter_mut(),
+        }
+    }
+}
+
+impl<'a, K, D> Iter<'a, K, D> {
+    /// Iterates the `len` nodes from `front` through `back`.
+    pub(crate) fn new(nodes: &'a [SkipNode<K, D>], front: NodeId, back: NodeId, len: usize) -> Self {
+        Iter {
+            nodes,
+            front,
+            back,
+            len,
+        }
+    }
+}
+
+impl<'a, K, D> Iterator for Iter<'a, K, D> {
+    type Item = (&'a K, &'a D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = &self.nodes[self.front as usize];
+        self.front = node.next().unwrap_or(HEAD);
+        Some(entry(node))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, D> DoubleEndedIterator for Iter<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let node = &self.nodes[self.back as usize];
+        self.back = node.backward;
+        Some(entry(node))
+    }
+}
+
+impl<K, D> ExactSizeIterator for Iter<'_, K, D> {}
+impl<K, D> FusedIterator for Iter<'_, K, D> {}
+
+impl<K, D> Clone for Iter<'_, K, D> {
+    fn clone(&self) -> Self {
+        Iter { ..*self }
+    }
+}
+
+impl<'a, K, D> IterMut<'a, K, D> {
+    /// Iterates the `len` nodes from `front` through `back`.
+    pub(crate) fn new(nodes: &'a mut [SkipNode<K, D>], front: NodeId, back: NodeId, len: usize) -> Self {
+        IterMut {
+            nodes: nodes.as_mut_ptr(),
+            front,
+            back,
+            len,
+            _marker: PhantomData,
+        }
+    }
+
+    fn take(&mut self, id: NodeId) -> (&'a K, &'a mut D) {
+        // SAFETY: `id` is a live slot of the arena borrowed for `'a`, and
+        // each node is taken once before `len` runs out.
+        let node = unsafe { &mut *self.nodes.add(id as usize) };
+        match (node.key.as_ref(), node.data.as_mut()) {
+            (Some(key), Some(data)) => (key, data),
+            _ => unreachable!("linked nodes hold an entry"),
+        }
+    }
+}
+
+impl<'a, K, D> Iterator for IterMut<'a, K, D> {
+    type Item = (&'a K, &'a mut D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let id = self.front;
+        // SAFETY: reading the link of a live slot, see `take`.
+        self.front = unsafe { (*self.nodes.add(id as usize)).next().unwrap_or(HEAD) };
+        Some(self.take(id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, D> DoubleEndedIterator for IterMut<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let id = self.back;
+        // SAFETY: reading the link of a live slot, see `take`.
+        self.back = unsafe { (*self.nodes.add(id as usize)).backward };
+        Some(self.take(id))
+    }
+}
+
+impl<K, D> ExactSizeIterator for IterMut<'_, K, D> {}
+impl<K, D> FusedIterator for IterMut<'_, K, D> {}
+
+// SAFETY: `IterMut` behaves like `&mut [SkipNode<K, D>]`.
+unsafe impl<K: Send, D: Send> Send for IterMut<'_, K, D> {}
+unsafe impl<K: Sync, D: Sync> Sync for IterMut<'_, K, D> {}
+
+impl<K, D> IntoIter<K, D> {
+    /// Iterates over the `len` entries of an arena detached from its list.
+    pub(crate) fn new(nodes: Vec<SkipNode<K, D>>, len: usize) -> Self {
+        let head = &nodes[HEAD as usize];
+        let (front, back) = (head.next().unwrap_or(HEAD), head.backward);
+        IntoIter {
+            nodes,
+            front,
+            back,
+            len,
+        }
+    }
+
+    fn take(&mut self, id: NodeId) -> (K, D) {
+        let node = &mut self.nodes[id as usize];
+        match (node.key.take(), node.data.take()) {
+            (Some(key), Some(data)) => (key, data),
+            _ => unreachable!("linked nodes hold an entry"),
+        }
+    }
+}
+
+impl<K, D> Iterator for IntoIter<K, D> {
+    type Item = (K, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let id = self.front;
+        self.front = self.nodes[id as usize].next().unwrap_or(HEAD);
+        Some(self.take(id))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, D> DoubleEndedIterator for IntoIter<K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let id = self.back;
+        self.back = self.nodes[id as usize].backward;
+        Some(self.take(id))
+    }
+}
+
+impl<K, D> ExactSizeIterator for IntoIter<K, D> {}
+impl<K, D> FusedIterator for IntoIter<K, D> {}
+
+impl<'a, K, D> Iterator for Keys<'a, K, D> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(key, _)| key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, D> DoubleEndedIterator for Keys<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(key, _)| key)
+    }
+}
+
+impl<K, D> ExactSizeIterator for Keys<'_, K, D> {}
+impl<K, D> FusedIterator for Keys<'_, K, D> {}
+
+impl<'a, K, D> Iterator for Values<'a, K, D> {
+    type Item = &'a D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, data)| data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, D> DoubleEndedIterator for Values<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, data)| data)
+    }
+}
+
+impl<K, D> ExactSizeIterator for Values<'_, K, D> {}
+impl<K, D> FusedIterator for Values<'_, K, D> {}
+
+impl<'a, K, D> Iterator for ValuesMut<'a, K, D> {
+    type Item = &'a mut D;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(_, data)| data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, D> DoubleEndedIterator for ValuesMut<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(_, data)| data)
+    }
+}
+
+impl<K, D> ExactSizeIterator for ValuesMut<'_, K, D> {}
+impl<K, D> FusedIterator for ValuesMut<'_, K, D> {}
+
+impl<'a, K, D, C, G> IntoIterator for &'a SkipList<K, D, C, G> {
+    type Item = (&'a K, &'a D);
+    type IntoIter = Iter<'a, K, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, D, C, G> IntoIterator for &'a mut SkipList<K, D, C, G> {
+    type Item = (&'a K, &'a mut D);
+    type IntoIter = IterMut<'a, K, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K, D, C, G> IntoIterator for SkipList<K, D, C, G> {
+    type Item = (K, D);
+    type IntoIter = IntoIter<K, D>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.nodes, self.width)
+    }
+}
@@ -0,0 +1,118 @@
+/// Source of the coin flips that decide how tall each new node's tower is.
+///
+/// Every `SkipList` owns its generator, so lists never share random state.
+pub trait LevelGenerator {
+    /// Creates a generator whose sequence is fully determined by `seed`.
+    fn from_seed(seed: u64) -> Self
+    where
+        Self: Sized;
+
+    /// Returns the next sample, uniformly distributed in `[0, 1]`.
+    fn next_f32(&mut self) -> f32;
+}
+
+/// Maps the top 24 bits of `x` to `[0, 1)`, the precision an `f32` can hold.
+fn u64_to_f32(x: u64) -> f32 {
+    (x >> 40) as f32 * (1.0 / (1u32 << 24) as f32)
+}
+
+/// xorshift64* generator, bit-for-bit the one `generate_data.py` uses to
+/// produce the benchmark inputs. This is the default generator.
+///
+/// The Python version lets `x << 25` grow past 64 bits before the `>> 27`
+/// step and only truncates afterwards; the same is done here in `u128` so
+/// both produce identical sequences.
+#[derive(Clone, Debug)]
+pub struct XorShift64Star {
+    state: u64,
+}
+
+impl XorShift64Star {
+    pub fn new(seed: u64) -> Self {
+        XorShift64Star {
+            state: if seed != 0 { seed } else { 1 },
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state as u128;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.state = x as u64;
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
+    }
+}
+
+impl LevelGenerator for XorShift64Star {
+    fn from_seed(seed: u64) -> Self {
+        XorShift64Star::new(seed)
+    }
+
+    fn next_f32(&mut self) -> f32 {
+        u64_to_f32(self.next_u64())
+    }
+}
+
+/// SplitMix64 generator. Unlike xorshift it accepts any seed, including 0.
+#[derive(Clone, Debug)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    pub fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+}
+
+impl LevelGenerator for SplitMix64 {
+    fn from_seed(seed: u64) -> Self {
+        SplitMix64::new(seed)
+    }
+
+    fn next_f32(&mut self) -> f32 {
+        u64_to_f32(self.next_u64())
+    }
+}
+
+/// Draws from the C library's `rand()`, reproducing the levels chosen by the
+/// C `jrsl.h` implementation for parity runs.
+///
+/// `rand()` is process-global: creating one reseeds it for the whole process,
+/// and lists using `CRand` on different threads interfere with each other.
+#[cfg(feature = "c-rand")]
+#[derive(Clone, Debug)]
+pub struct CRand {
+    _private: (),
+}
+
+#[cfg(feature = "c-rand")]
+impl CRand {
+    /// Calls `srand(seed)`.
+    pub fn new(seed: u32) -> Self {
+        unsafe {
+            libc::srand(seed);
+        }
+        CRand { _private: () }
+    }
+}
+
+#[cfg(feature = "c-rand")]
+impl LevelGenerator for CRand {
+    fn from_seed(seed: u64) -> Self {
+        CRand::new(seed as u32)
+    }
+
+    fn next_f32(&mut self) -> f32 {
+        unsafe { libc::rand() as f32 / libc::RAND_MAX as f32 }
+    }
+}
@@ -0,0 +1,38 @@
+//! An indexable skip list ported from the C `jrsl.h` implementation.
+//!
+//! Every link in the list records how many level-0 nodes it spans, so in
+//! addition to ordered insert/search/remove the list supports access by
+//! position in `O(log n)`.
+
+mod builder;
+mod bulk;
+mod comparator;
+mod drain;
+mod entry;
+mod error;
+mod export;
+mod iter;
+mod level;
+mod range;
+mod render;
+mod set_ops;
+mod skiplist;
+mod stats;
+mod traits;
+mod validate;
+
+pub use builder::{
+    Growth, SkipListBuilder, DEFAULT_MAX_LEVEL, DEFAULT_PROBABILITY, MIN_MAX_LEVEL,
+};
+pub use comparator::{Comparator, OrdComparator};
+pub use drain::{DrainRange, ExtractIf};
+pub use entry::{Entry, OccupiedEntry, VacantEntry};
+pub use error::{BuildError, ConfigError, OrderError, ValidationError, Violation};
+pub use iter::{IntoIter, Iter, IterMut, Keys, Values, ValuesMut};
+#[cfg(feature = "c-rand")]
+pub use level::CRand;
+pub use level::{LevelGenerator, SplitMix64, XorShift64Star};
+pub use range::{Range, RangeMut};
+pub use render::RenderOptions;
+pub use skiplist::{get_max_level, SkipList};
+pub use stats::Stats;
@@ -0,0 +1,157 @@
+use std::borrow::Borrow;
+use std::iter::FusedIterator;
+use std::ops::{Bound, RangeBounds};
+
+use crate::iter::{Iter, IterMut};
+use crate::comparator::Comparator;
+use crate::level::LevelGenerator;
+use crate::skiplist::{NodeId, SkipList, HEAD};
+
+/// Iterator over the entries whose keys fall in a range, created by `SkipList::range`.
+pub struct Range<'a, K, D> {
+    inner: Iter<'a, K, D>,
+}
+
+/// Mutable iterator over the entries whose keys fall in a range, created by
+/// `SkipList::range_mut`.
+pub struct RangeMut<'a, K, D> {
+    inner: IterMut<'a, K, D>,
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// The last node before the start of the range and its position.
+    fn start_of<Q: ?Sized>(&self, bound: Bound<&Q>) -> (NodeId, usize)
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        match bound {
+            Bound::Included(key) => self.last_before(key, false),
+            Bound::Excluded(key) => self.last_before(key, true),
+            Bound::Unbounded => (HEAD, 0),
+        }
+    }
+
+    /// The last node inside the range, or before it if it is empty, and its position.
+    fn end_of<Q: ?Sized>(&self, bound: Bound<&Q>) -> (NodeId, usize)
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        match bound {
+            Bound::Included(key) => self.last_before(key, true),
+            Bound::Excluded(key) => self.last_before(key, false),
+            Bound::Unbounded => (self.node(HEAD).backward, self.width),
+        }
+    }
+
+    /// Finds the first and last node inside `range` and how many nodes lie
+    /// between them, from two descents and the widths summed along them.
+    fn span<Q: ?Sized, R: RangeBounds<Q>>(&self, range: &R) -> (NodeId, NodeId, usize)
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (before_start, start) = self.start_of(range.start_bound());
+        let (last, end) = self.end_of(range.end_bound());
+        let first = self.node(before_start).next().unwrap_or(HEAD);
+        (first, last, end.saturating_sub(start))
+    }
+
+    /// The index of the first entry inside `range` and how many there are.
+    pub(crate) fn index_span<Q: ?Sized, R: RangeBounds<Q>>(&self, range: &R) -> (usize, usize)
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (_, start) = self.start_of(range.start_bound());
+        let (_, end) = self.end_of(range.end_bound());
+        (start, end.saturating_sub(start))
+    }
+
+    /// Iterates over the entries whose keys fall in `range`, in `O(log n)` plus
+    /// the entries visited. A range whose start lies after its end is empty.
+    pub fn range<Q: ?Sized, R: RangeBounds<Q>>(&self, range: R) -> Range<'_, K, D>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (first, last, len) = self.span(&range);
+        Range {
+            inner: Iter::new(&self.nodes, first, last, len),
+        }
+    }
+
+    /// Like `range`, but yields mutable references to the data.
+    pub fn range_mut<Q: ?Sized, R: RangeBounds<Q>>(&mut self, range: R) -> RangeMut<'_, K, D>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (first, last, len) = self.span(&range);
+        RangeMut {
+            inner: IterMut::new(&mut self.nodes, first, last, len),
+        }
+    }
+
+    /// Counts the entries whose keys fall in `range` in `O(log n)`, using the
+    /// link widths instead of walking the entries.
+    pub fn count_range<Q: ?Sized, R: RangeBounds<Q>>(&self, range: R) -> usize
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (_, _, len) = self.span(&range);
+        len
+    }
+}
+
+impl<'a, K, D> Iterator for Range<'a, K, D> {
+    type Item = (&'a K, &'a D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, D> DoubleEndedIterator for Range<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<K, D> ExactSizeIterator for Range<'_, K, D> {}
+impl<K, D> FusedIterator for Range<'_, K, D> {}
+
+impl<K, D> Clone for Range<'_, K, D> {
+    fn clone(&self) -> Self {
+        Range {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<'a, K, D> Iterator for RangeMut<'a, K, D> {
+    type Item = (&'a K, &'a mut D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, D> DoubleEndedIterator for RangeMut<'_, K, D> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<K, D> ExactSizeIterator for RangeMut<'_, K, D> {}
+impl<K, D> FusedIterator for RangeMut<'_, K, D> {}
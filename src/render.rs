@@ -0,0 +1,140 @@
+use std::fmt::{self, Write};
+use std::io;
+
+use crate::skiplist::{SkipList, HEAD};
+
+/// Formats the label of an entry.
+type Label<'a, K, D> = Box<dyn Fn(&K, &D) -> String + 'a>;
+
+/// Layout of the text drawn by `SkipList::render`.
+pub struct RenderOptions<'a, K, D> {
+    cell_width: usize,
+    max_levels: Option<usize>,
+    max_nodes: Option<usize>,
+    label: Option<Label<'a, K, D>>,
+}
+
+impl<'a, K, D> RenderOptions<'a, K, D> {
+    /// Six columns per node, every level and node, no labels.
+    pub fn new() -> Self {
+        RenderOptions {
+            cell_width: 6,
+            max_levels: None,
+            max_nodes: None,
+            label: None,
+        }
+    }
+
+    /// Columns per node, at least 3 so an arrow still fits.
+    pub fn cell_width(mut self, cell_width: usize) -> Self {
+        self.cell_width = cell_width.max(3);
+        self
+    }
+
+    /// Draws only the lowest `max_levels` levels.
+    pub fn max_levels(mut self, max_levels: usize) -> Self {
+        self.max_levels = Some(max_levels);
+        self
+    }
+
+    /// Draws only the first `max_nodes` nodes, eliding the rest with `...`.
+    pub fn max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = Some(max_nodes);
+        self
+    }
+
+    /// Adds a row of labels under the towers, each right-aligned in its cell.
+    pub fn label<F: Fn(&K, &D) -> String + 'a>(mut self, label: F) -> Self {
+        self.label = Some(Box::new(label));
+        self
+    }
+}
+
+impl<K, D> Default for RenderOptions<'_, K, D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, D, C, G> SkipList<K, D, C, G> {
+    /// Draws the towers as text, top level first: a row of link widths over a
+    /// row of arrows for every level, then the labels if `options` has any.
+    pub fn render<W: Write>(
+        &self,
+        out: &mut W,
+        options: &RenderOptions<'_, K, D>,
+    ) -> fmt::Result {
+        let cell = options.cell_width;
+        let shown = options.max_nodes.map_or(self.width, |max| max.min(self.width));
+        let elided = shown < self.width;
+        let levels = options.max_levels.map_or(self.level(), |max| max.min(self.level()));
+        if levels < self.level() {
+            writeln!(out, "... {} levels above", self.level() - levels)?;
+        }
+
+        for level in (0..levels).rev() {
+            let mut widths = String::new();
+            let mut arrows = String::new();
+            // Position of `current` along level 0, the head being 0
+            let (mut current, mut position) = (HEAD, 0);
+            loop {
+                let link = self.node(current).link(level);
+                let Some(next) = link.node else {
+                    arrows.push('x');
+                    break;
+                };
+                if position + link.width > shown {
+                    // The link leaves the drawn nodes, cut it at the edge
+                    let span = (shown + 1 - position) * cell;
+                    let _ = write!(widths, "{:^span$}", link.width, span = span);
+                    let _ = write!(arrows, "o{:-<dashes$}...", "", dashes = span - 1);
+                    break;
+                }
+                let span = link.width * cell;
+                let _ = write!(widths, "{:^span$}", link.width, span = span);
+                let _ = write!(arrows, "o{:-<dashes$}> ", "", dashes = span - 3);
+                current = next;
+                position += link.width;
+            }
+            writeln!(out, "{}", widths.trim_end())?;
+            writeln!(out, "{}  Level {}", arrows, level)?;
+        }
+
+        if let Some(label) = &options.label {
+            let mut labels = String::new();
+            for (key, data) in self.iter().take(shown) {
+                let _ = write!(labels, "{:>cell$}", label(key, data), cell = cell);
+            }
+            if elided {
+                labels.push_str(" ...");
+            }
+            writeln!(out, "{}", labels)?;
+        }
+        Ok(())
+    }
+
+    /// Like `render`, writing to an `io::Write` sink such as a file or stdout.
+    pub fn render_io<W: io::Write>(
+        &self,
+        out: &mut W,
+        options: &RenderOptions<'_, K, D>,
+    ) -> io::Result<()> {
+        let mut text = String::new();
+        let _ = self.render(&mut text, options);
+        out.write_all(text.as_bytes())
+    }
+
+    /// Prints the structure to stdout, then calls `label_printer` for every
+    /// entry in order, which is expected to print a cell of 6 columns.
+    pub fn display_list(&self, label_printer: Option<fn(&K, &D)>) {
+        let _ = self.render_io(&mut io::stdout(), &RenderOptions::new());
+        if let Some(printer) = label_printer {
+            if self.width > 0 {
+                for (key, data) in self.iter() {
+                    printer(key, data);
+                }
+                println!();
+            }
+        }
+    }
+}
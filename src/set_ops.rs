@@ -0,0 +1,112 @@
+use std::cmp::Ordering;
+
+use crate::comparator::Comparator;
+use crate::level::LevelGenerator;
+use crate::skiplist::SkipList;
+
+/// One step of walking two lists side by side in key order.
+enum Merged<K, D> {
+    Left(K, D),
+    Right(K, D),
+    Both(K, D, D),
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Walks the entries of `self` and `other` in key order, emptying both,
+    /// and rebuilds `self` from the entries `pick` keeps. Every entry is
+    /// appended after the last one, so this is `O(n + m)`.
+    fn merge<F>(&mut self, other: &mut Self, mut pick: F)
+    where
+        F: FnMut(Merged<K, D>) -> Option<(K, D)>,
+    {
+        let mut left = self.take_all().peekable();
+        let mut right = other.take_all().peekable();
+        let (mut tail, mut position) = self.find_tail();
+
+        loop {
+            let order = match (left.peek(), right.peek()) {
+                (Some((a, _)), Some((b, _))) => self.compare_keys(a, b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+            let merged = match order {
+                Ordering::Less => left.next().map(|(key, data)| Merged::Left(key, data)),
+                Ordering::Greater => right.next().map(|(key, data)| Merged::Right(key, data)),
+                Ordering::Equal => match (left.next(), right.next()) {
+                    (Some((key, ours)), Some((_, theirs))) => Some(Merged::Both(key, ours, theirs)),
+                    _ => unreachable!("both sides were peeked"),
+                },
+            };
+            if let Some((key, data)) = merged.and_then(&mut pick) {
+                self.push_back_unchecked(&mut tail, &mut position, key, data);
+            }
+        }
+    }
+
+    /// Moves every entry of `other` into `self`, leaving `other` empty. Like
+    /// `BTreeMap::append`, the data from `other` wins for keys in both.
+    pub fn append(&mut self, other: &mut Self) {
+        self.append_with(other, |_, _, theirs| theirs);
+    }
+
+    /// Like `append`, but `resolve(key, ours, theirs)` decides the data kept
+    /// for keys in both lists.
+    pub fn append_with<F>(&mut self, other: &mut Self, mut resolve: F)
+    where
+        F: FnMut(&K, D, D) -> D,
+    {
+        self.merge(other, |merged| match merged {
+            Merged::Left(key, data) | Merged::Right(key, data) => Some((key, data)),
+            Merged::Both(key, ours, theirs) => {
+                let data = resolve(&key, ours, theirs);
+                Some((key, data))
+            }
+        });
+    }
+
+    /// Entries whose keys are in either list, with `resolve(key, ours, theirs)`
+    /// combining the data of keys in both. The result keeps the configuration
+    /// of `self`; clone the lists first to keep the originals.
+    pub fn union<F>(mut self, mut other: Self, resolve: F) -> Self
+    where
+        F: FnMut(&K, D, D) -> D,
+    {
+        self.append_with(&mut other, resolve);
+        self
+    }
+
+    /// Entries whose keys are in both lists, with their data combined by
+    /// `resolve(key, ours, theirs)`.
+    pub fn intersection<F>(mut self, mut other: Self, mut resolve: F) -> Self
+    where
+        F: FnMut(&K, D, D) -> D,
+    {
+        self.merge(&mut other, |merged| match merged {
+            Merged::Both(key, ours, theirs) => {
+                let data = resolve(&key, ours, theirs);
+                Some((key, data))
+            }
+            _ => None,
+        });
+        self
+    }
+
+    /// Entries of `self` whose keys are not in `other`.
+    pub fn difference(mut self, mut other: Self) -> Self {
+        self.merge(&mut other, |merged| match merged {
+            Merged::Left(key, data) => Some((key, data)),
+            _ => None,
+        });
+        self
+    }
+
+    /// Entries whose keys are in exactly one of the lists.
+    pub fn symmetric_difference(mut self, mut other: Self) -> Self {
+        self.merge(&mut other, |merged| match merged {
+            Merged::Left(key, data) | Merged::Right(key, data) => Some((key, data)),
+            Merged::Both(..) => None,
+        });
+        self
+    }
+}
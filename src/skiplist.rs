@@ -1,110 +1,389 @@
-use std::rc::Rc;
-use std::cell::RefCell;
+use std::borrow::Borrow;
 use std::cmp::Ordering;
-use libc;
+use std::ops::{Index, IndexMut};
 
+use crate::builder::{
+    check_config, check_probability, Growth, DEFAULT_MAX_LEVEL, DEFAULT_PROBABILITY, MIN_MAX_LEVEL,
+};
+use crate::error::ConfigError;
+use crate::comparator::{Comparator, OrdComparator};
+use crate::iter::IntoIter;
+use crate::level::{LevelGenerator, XorShift64Star};
+
+/// Index of a node in `SkipList::nodes`.
+pub(crate) type NodeId = u32;
+
+/// The head node always occupies the first slot of the arena.
+pub(crate) const HEAD: NodeId = 0;
+
+#[derive(Clone, Copy)]
+pub(crate) struct Link {
+    pub(crate) width: usize,
+    pub(crate) node: Option<NodeId>,
+}
+
+const EMPTY_LINK: Link = Link {
+    width: 0,
+    node: None,
+};
+
+/// Number of links stored inline in a node slot. With `p = 0.5` this covers
+/// 15 out of 16 nodes, so most hops read the key and the link from the same
+/// slot instead of chasing a second heap allocation.
+const INLINE_LINKS: usize = 4;
+
+/// The forward links of a node, lowest levels first.
 #[derive(Clone)]
-struct Link<K, D> {
-    width: usize,
-    node: Option<Rc<RefCell<SkipNode<K, D>>>>,
+struct Tower {
+    len: usize,
+    inline: [Link; INLINE_LINKS],
+    rest: Vec<Link>,
+}
+
+impl Tower {
+    fn new(level: usize) -> Self {
+        Tower {
+            len: level,
+            inline: [EMPTY_LINK; INLINE_LINKS],
+            rest: vec![EMPTY_LINK; level.saturating_sub(INLINE_LINKS)],
+        }
+    }
+
+    /// Sets the number of links, new ones being null.
+    fn resize(&mut self, len: usize) {
+        for link in self.inline.iter_mut().skip(len) {
+            *link = EMPTY_LINK;
+        }
+        self.rest.resize(len.saturating_sub(INLINE_LINKS), EMPTY_LINK);
+        self.len = len;
+    }
+
+    fn get(&self, level: usize) -> Option<&Link> {
+        if level < self.len {
+            Some(&self[level])
+        } else {
+            None
+        }
+    }
+}
+
+impl Index<usize> for Tower {
+    type Output = Link;
+
+    #[inline]
+    fn index(&self, level: usize) -> &Link {
+        debug_assert!(level < self.len);
+        if level < INLINE_LINKS {
+            &self.inline[level]
+        } else {
+            &self.rest[level - INLINE_LINKS]
+        }
+    }
 }
 
-struct SkipNode<K, D> {
-    forward: Vec<Link<K, D>>,
-    key: Option<K>,
-    data: Option<D>,
+impl IndexMut<usize> for Tower {
+    #[inline]
+    fn index_mut(&mut self, level: usize) -> &mut Link {
+        debug_assert!(level < self.len);
+        if level < INLINE_LINKS {
+            &mut self.inline[level]
+        } else {
+            &mut self.rest[level - INLINE_LINKS]
+        }
+    }
 }
 
-pub struct SkipList<K, D> {
+#[derive(Clone)]
+pub(crate) struct SkipNode<K, D> {
+    forward: Tower,
+    // Previous node on level 0. The head's `backward` is the last node, or
+    // the head itself when the list is empty.
+    pub(crate) backward: NodeId,
+    pub(crate) key: Option<K>,
+    pub(crate) data: Option<D>,
+}
+
+impl<K, D> SkipNode<K, D> {
+    /// Next node on level 0.
+    pub(crate) fn next(&self) -> Option<NodeId> {
+        self.forward[0].node
+    }
+
+    /// Number of levels the node is linked on.
+    pub(crate) fn height(&self) -> usize {
+        self.forward.len
+    }
+
+    /// Forward link on `level`, below `height`.
+    pub(crate) fn link(&self, level: usize) -> Link {
+        self.forward[level]
+    }
+
+    /// Heap bytes held by the links that do not fit inline.
+    pub(crate) fn spilled_bytes(&self) -> usize {
+        self.forward.rest.capacity() * std::mem::size_of::<Link>()
+    }
+
+    /// Key and data of the node, `None` for the head.
+    fn entry(&self) -> Option<(&K, &D)> {
+        match (self.key.as_ref(), self.data.as_ref()) {
+            (Some(key), Some(data)) => Some((key, data)),
+            _ => None,
+        }
+    }
+}
+
+/// Nodes are owned by a flat arena and link to each other by index, so
+/// dropping or clearing a list of any length never recurses, and cloning
+/// copies the arena into an independent list with the same towers.
+#[derive(Clone)]
+pub struct SkipList<K, D, C = OrdComparator, G = XorShift64Star> {
     max_level: u16,
     p: f32,
+    growth: Growth,
     level: u16,
-    width: usize,
-    head: Rc<RefCell<SkipNode<K, D>>>,
-    comparator: fn(&K, &K) -> std::cmp::Ordering,
+    pub(crate) width: usize,
+    // Node arena, slot `HEAD` is the head. Removed nodes are emptied and their
+    // slot recorded in `free` for reuse by later inserts.
+    pub(crate) nodes: Vec<SkipNode<K, D>>,
+    free: Vec<NodeId>,
+    comparator: C,
+    rng: G,
 }
 
-pub fn get_max_level(n: usize, p: f32) -> u16 {
-    assert!((0.0..=1.0).contains(&p));
+/// Default seed of the level generator, the value the C benchmark passes to `srand`.
+pub(crate) const DEFAULT_SEED: u64 = 42;
+
+/// Levels a list of `n` entries calls for at probability `p`, `log_{1/p}(n)`
+/// and at least `MIN_MAX_LEVEL`, ready to pass as `max_level`. Fails like
+/// the builder if `p` is not strictly between 0 and 1.
+pub fn get_max_level(n: usize, p: f32) -> Result<u16, ConfigError> {
+    check_probability(p)?;
     let level = ((n as f32).ln() / (1.0 / p).ln()) as u16;
-    level.max(1)
+    Ok(level.max(MIN_MAX_LEVEL))
 }
 
-impl<K, D> SkipList<K, D> {
+impl<K, D, C: Comparator<K>> SkipList<K, D, C> {
+    /// Creates a list like the C `jrsl_initialize`. Panics with the
+    /// `ConfigError` message on parameters `SkipList::builder` would reject.
     pub fn new(
         max_level: u16,
         p: f32,
-        comparator: fn(&K, &K) -> Ordering,
+        comparator: C,
     ) -> Self {
-        let mut forward = Vec::with_capacity(max_level as usize);
-        for _ in 0..max_level {
-            forward.push(Link {
-                width: 0,
-                node: None,
-            });
+        if let Err(error) = check_config(max_level, p) {
+            panic!("{}", error);
         }
+        Self::with_generator(
+            max_level,
+            p,
+            Growth::Fixed,
+            comparator,
+            XorShift64Star::new(DEFAULT_SEED),
+        )
+    }
+}
 
-        unsafe {
-            libc::srand(42);
-        }
+/// Arena access, which needs neither the comparator nor the level generator.
+impl<K, D, C, G> SkipList<K, D, C, G> {
+    pub(crate) fn node(&self, id: NodeId) -> &SkipNode<K, D> {
+        &self.nodes[id as usize]
+    }
+
+    pub(crate) fn node_mut(&mut self, id: NodeId) -> &mut SkipNode<K, D> {
+        &mut self.nodes[id as usize]
+    }
+
+    /// Levels a node can be linked on.
+    pub(crate) fn max_level(&self) -> usize {
+        self.max_level as usize
+    }
+
+    /// Levels currently in use, the height of the tallest node.
+    pub(crate) fn level(&self) -> usize {
+        self.level as usize
+    }
 
-        let head = Rc::new(RefCell::new(SkipNode {
-            forward,
+    /// Probability of a new node reaching each next level.
+    pub(crate) fn probability(&self) -> f32 {
+        self.p
+    }
+
+    pub(crate) fn free_slots(&self) -> &[NodeId] {
+        &self.free
+    }
+
+    /// Empties an unlinked node's slot and returns its key and data.
+    pub(crate) fn release(&mut self, id: NodeId) -> (K, D) {
+        let node = self.node_mut(id);
+        node.forward = Tower::new(0);
+        let key = node.key.take();
+        let data = node.data.take();
+        self.free.push(id);
+        (
+            key.expect("released node has a key"),
+            data.expect("released node has data"),
+        )
+    }
+}
+
+/// An empty list with the builder's defaults, for comparators that need no state.
+impl<K, D, C: Comparator<K> + Default, G: LevelGenerator> Default for SkipList<K, D, C, G> {
+    fn default() -> Self {
+        Self::with_generator(
+            DEFAULT_MAX_LEVEL,
+            DEFAULT_PROBABILITY,
+            Growth::Fixed,
+            C::default(),
+            G::from_seed(DEFAULT_SEED),
+        )
+    }
+}
+
+impl<K, D, C: Comparator<K> + Default, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// An empty list sized for about `expected_len` entries, which keeps
+    /// growing its `max_level` past that with `Growth::Grow`. The length is
+    /// only a hint, the list holds any number of entries in `O(log n)`.
+    pub fn with_expected_len(expected_len: usize) -> Self {
+        let max_level = get_max_level(expected_len, DEFAULT_PROBABILITY)
+            .expect("the default probability is valid");
+        Self::with_generator(
+            max_level + 1,
+            DEFAULT_PROBABILITY,
+            Growth::Grow,
+            C::default(),
+            G::from_seed(DEFAULT_SEED),
+        )
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    pub(crate) fn with_generator(
+        max_level: u16,
+        p: f32,
+        growth: Growth,
+        comparator: C,
+        rng: G,
+    ) -> Self {
+        let head = SkipNode {
+            forward: Tower::new(max_level as usize),
+            backward: HEAD,
             key: None,
             data: None,
-        }));
+        };
 
         SkipList {
             max_level,
             p,
+            growth,
             level: 1, // Start with level 1 like C version
             width: 0,
-            head,
+            nodes: vec![head],
+            free: Vec::new(),
             comparator,
+            rng,
         }
     }
-    
-    fn random_level(&self) -> usize {
+
+    fn random_level(&mut self) -> usize {
         let mut lvl= 1;
-        let mut rnd: f32 = unsafe { libc::rand() as f32 / libc::RAND_MAX as f32 };
+        let mut rnd = self.rng.next_f32();
         while rnd < self.p && lvl < self.max_level - 1 {
             lvl += 1;
-            rnd = unsafe { libc::rand() as f32 / libc::RAND_MAX as f32 };
+            rnd = self.rng.next_f32();
         }
         lvl as usize
     }
+
+    /// A new generator seeded from this one's next draws, so a list split
+    /// off this one does not repeat its tower heights.
+    fn fork_rng(&mut self) -> G {
+        let mut seed = 0;
+        for _ in 0..3 {
+            seed = (seed << 24) | (self.rng.next_f32() * (1 << 24) as f32) as u64;
+        }
+        G::from_seed(seed)
+    }
+
+    /// Number of entries towers of `levels` levels are enough for, `(1/p)^levels`.
+    fn capacity(&self, levels: u16) -> f64 {
+        (1.0 / self.p as f64).powi(levels as i32)
+    }
+
+    /// Under `Growth::Grow` and `GrowAndShrink`, adds head levels until
+    /// towers can reach the levels one more entry calls for. The `update`
+    /// and `position` vectors of the insert in progress get the head on the
+    /// new levels.
+    fn grow_max_level(&mut self, update: &mut Vec<NodeId>, position: &mut Vec<usize>) {
+        if self.growth == Growth::Fixed || !(0.0..1.0).contains(&self.p) {
+            return;
+        }
+        let len = (self.width + 1) as f64;
+        while self.max_level < u16::MAX && self.capacity(self.max_level.saturating_sub(1)) < len {
+            self.max_level += 1;
+            self.nodes[HEAD as usize].forward.resize(self.max_level as usize);
+            update.push(HEAD);
+            position.push(0);
+        }
+    }
+
+    pub(crate) fn compare_keys(&self, a: &K, b: &K) -> Ordering {
+        self.comparator.compare(a, b)
+    }
+
+    /// Compares the key of `node` against `key`. The head sorts before every key.
+    pub(crate) fn compare<Q: ?Sized>(&self, node: NodeId, key: &Q) -> Ordering
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        match self.node(node).key.as_ref() {
+            Some(node_key) => self.comparator.compare(node_key.borrow(), key),
+            None => Ordering::Less,
+        }
+    }
+
+    /// Places a new node in a free slot, or at the end of the arena.
+    fn alloc(&mut self, key: K, data: D, level: usize, backward: NodeId) -> NodeId {
+        match self.free.pop() {
+            Some(id) => {
+                let node = self.node_mut(id);
+                node.forward = Tower::new(level);
+                node.backward = backward;
+                node.key = Some(key);
+                node.data = Some(data);
+                id
+            }
+            None => {
+                let id = NodeId::try_from(self.nodes.len())
+                    .expect("skip list node arena exceeds u32::MAX slots");
+                self.nodes.push(SkipNode {
+                    forward: Tower::new(level),
+                    backward,
+                    key: Some(key),
+                    data: Some(data),
+                });
+                id
+            }
+        }
+    }
 }
 
-impl<K: Clone, D: Clone> SkipList<K, D> {
-    pub fn search(&self, key: &K) -> Option<D> {
-        let mut current = self.head.clone();
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    fn find_node<Q: ?Sized>(&self, key: &Q) -> Option<NodeId>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let mut current = HEAD;
 
         for i in (0..self.level as usize).rev() {
-            loop {
-                let next_node_rc = {
-                    let current_borrowed = current.borrow();
-                    current_borrowed.forward[i].node.clone()
-                };
-                
-                if let Some(next_rc) = next_node_rc {
-                    let next_node = next_rc.borrow();
-                    if let Some(next_key) = next_node.key.as_ref() {
-                        match (self.comparator)(next_key, key) {
-                            Ordering::Less => {
-                                drop(next_node);
-                                current = next_rc;
-                            }
-                            Ordering::Equal => {
-                                // Found the key at this level! Return immediately
-                                return next_node.data.clone();
-                            }
-                            Ordering::Greater => break,
-                        }
-                    } else {
-                        break;
-                    }
-                } else {
-                    break;
+            while let Some(next) = self.node(current).forward[i].node {
+                match self.compare(next, key) {
+                    Ordering::Less => current = next,
+                    // Found the key at this level! Return immediately
+                    Ordering::Equal => return Some(next),
+                    Ordering::Greater => break,
                 }
             }
         }
@@ -112,360 +391,698 @@ impl<K: Clone, D: Clone> SkipList<K, D> {
         None
     }
 
-    pub fn insert(&mut self, key: K, data: D) -> Option<D> {
-        let mut update: Vec<Rc<RefCell<SkipNode<K, D>>>> = vec![self.head.clone(); self.max_level as usize];
+    /// Finds the last node whose key is less than `key`, or less than or
+    /// equal to it when `or_equal` is set, and its 1-based position obtained
+    /// by summing link widths. Returns the head and 0 if there is none.
+    pub(crate) fn last_before<Q: ?Sized>(&self, key: &Q, or_equal: bool) -> (NodeId, usize)
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let mut current = HEAD;
+        let mut position = 0;
+
+        for i in (0..self.level as usize).rev() {
+            while let Link { width, node: Some(next) } = self.node(current).forward[i] {
+                match self.compare(next, key) {
+                    Ordering::Less => {}
+                    Ordering::Equal if or_equal => {}
+                    _ => break,
+                }
+                position += width;
+                current = next;
+            }
+        }
+
+        (current, position)
+    }
+
+    /// For every level, finds the last node whose key is less than `key`
+    /// together with the level-0 distance walked on that level. Levels above
+    /// `self.level` are left pointing at the head.
+    pub(crate) fn find_update<Q: ?Sized>(&self, key: &Q) -> (Vec<NodeId>, Vec<usize>)
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let mut update: Vec<NodeId> = vec![HEAD; self.max_level as usize];
         let mut update_width: Vec<usize> = vec![0; self.max_level as usize];
-        let mut current = self.head.clone();
+        let mut current = HEAD;
 
         // Search for insertion point, following C implementation logic
-        for i in (0..self.level).rev() {
+        for i in (0..self.level as usize).rev() {
             let mut width_sum = 0;
 
-            loop {
-                let next_info = {
-                    let current_borrowed = current.borrow();
-                    let link = &current_borrowed.forward[i as usize];
-                    if let Some(node) = link.node.as_ref() {
-                        Some((node.clone(), link.width))
-                    } else {
-                        None
-                    }
-                };
+            while let Some(next) = self.node(current).forward[i].node {
+                if self.compare(next, key) != Ordering::Less {
+                    break;
+                }
+                width_sum += self.node(current).forward[i].width;
+                current = next;
+            }
+            update[i] = current;
+            update_width[i] = width_sum;
+        }
 
-                if let Some((next_rc, width)) = next_info {
-                    let next_node = next_rc.borrow();
-                    if let Some(next_key) = next_node.key.as_ref() {
-                        if (self.comparator)(next_key, &key) == Ordering::Less {
-                            drop(next_node);
-                            width_sum += width;
-                            current = next_rc;
-                            continue;
-                        }
-                    }
+        (update, update_width)
+    }
+
+    /// The last node on every level and its position, the `update` vectors
+    /// for appending after the last entry. Levels above `self.level` are left
+    /// pointing at the head.
+    pub(crate) fn find_tail(&self) -> (Vec<NodeId>, Vec<usize>) {
+        let mut tail: Vec<NodeId> = vec![HEAD; self.max_level as usize];
+        let mut position: Vec<usize> = vec![0; self.max_level as usize];
+        let mut current = HEAD;
+        let mut traversed = 0;
+
+        for i in (0..self.level as usize).rev() {
+            while let Link { width, node: Some(next) } = self.node(current).forward[i] {
+                traversed += width;
+                current = next;
+            }
+            tail[i] = current;
+            position[i] = traversed;
+        }
+
+        (tail, position)
+    }
+
+    /// Like `find_update`, but finds the predecessors of the node at `index`
+    /// together with the position of each.
+    fn find_update_at(&self, index: usize) -> (Vec<NodeId>, Vec<usize>) {
+        let mut update: Vec<NodeId> = vec![HEAD; self.max_level as usize];
+        let mut position: Vec<usize> = vec![0; self.max_level as usize];
+        let mut traversed = 0;
+        let mut current = HEAD;
+
+        for i in (0..self.level as usize).rev() {
+            while let Link { width, node: Some(next) } = self.node(current).forward[i] {
+                if traversed + width > index {
+                    break;
                 }
-                break;
+                traversed += width;
+                current = next;
             }
-            update[i as usize] = current.clone();
-            update_width[i as usize] = width_sum;
+            update[i] = current;
+            position[i] = traversed;
         }
 
+        (update, position)
+    }
+
+    /// Borrows the data stored under `key`. As with `BTreeMap`, this and the
+    /// other lookups accept any borrowed form of the key the comparator can
+    /// order, such as `&str` for a list of `String`s.
+    pub fn get<Q: ?Sized>(&self, key: &Q) -> Option<&D>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let id = self.find_node(key)?;
+        self.node(id).data.as_ref()
+    }
+
+    /// Mutably borrows the data stored under `key`.
+    pub fn get_mut<Q: ?Sized>(&mut self, key: &Q) -> Option<&mut D>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let id = self.find_node(key)?;
+        self.node_mut(id).data.as_mut()
+    }
+
+    pub fn contains_key<Q: ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        self.find_node(key).is_some()
+    }
+
+    pub fn insert(&mut self, key: K, data: D) -> Option<D> {
+        self.insert_with_rank(key, data).1
+    }
+
+    /// Like `insert`, but also returns the index the key ended up at.
+    pub fn insert_with_rank(&mut self, key: K, data: D) -> (usize, Option<D>) {
+        let (mut update, mut update_width) = self.find_update(&key);
+        // The widths walked on every level add up to the predecessor's position
+        let rank = update_width[..self.level as usize].iter().sum();
+
         // Check if key already exists
-        {
-            let current_borrowed = current.borrow();
-            if let Some(next_rc) = current_borrowed.forward[0].node.as_ref() {
-                let mut next_node = next_rc.borrow_mut();
-                if let Some(next_key) = next_node.key.as_ref() {
-                    if (self.comparator)(next_key, &key) == Ordering::Equal {
-                        let old_data = next_node.data.replace(data);
-                        return old_data;
-                    }
-                }
+        if let Some(next) = self.node(update[0]).forward[0].node {
+            if self.compare(next, &key) == Ordering::Equal {
+                return (rank, self.node_mut(next).data.replace(data));
             }
         }
-        // drop(current_borrowed);
 
+        self.link_new(&mut update, &mut update_width, key, data);
+        (rank, None)
+    }
+
+    /// Creates a node for a key that is not in the list yet and links it in
+    /// after `update`, as computed by `find_update` for that key.
+    pub(crate) fn link_new(
+        &mut self,
+        update: &mut Vec<NodeId>,
+        update_width: &mut Vec<usize>,
+        key: K,
+        data: D,
+    ) -> NodeId {
+        self.grow_max_level(update, update_width);
         let node_level = self.random_level();
 
         // Update level if necessary
         if node_level > self.level as usize {
             for i in self.level as usize..node_level {
-                update[i] = self.head.clone();
+                update[i] = HEAD;
                 update_width[i] = 0;
                 // Initialize head's forward links for new levels
-                self.head.borrow_mut().forward[i] = Link {
-                    width: 0,
-                    node: None,
-                };
+                self.node_mut(HEAD).forward[i] = EMPTY_LINK;
             }
             self.level = node_level as u16;
         }
 
         // Create new node
-        let mut new_forward = Vec::with_capacity(node_level);
-        for _ in 0..node_level {
-            new_forward.push(Link {
-                width: 0,
-                node: None,
-            });
-        }
-
-        let new_node = Rc::new(RefCell::new(SkipNode {
-            forward: new_forward,
-            key: Some(key),
-            data: Some(data),
-        }));
+        let new_node = self.alloc(key, data, node_level, update[0]);
 
         // Insert new node - following C implementation logic
         for i in 0..node_level {
-            let old_link = {
-                let mut upd = update[i].borrow_mut();
-                std::mem::replace(&mut upd.forward[i], Link {
+            let old_link = std::mem::replace(
+                &mut self.node_mut(update[i]).forward[i],
+                Link {
                     width: 0,
-                    node: Some(new_node.clone()),
-                })
-            };
-
-            // Set new node's forward link
-            new_node.borrow_mut().forward[i] = old_link;
+                    node: Some(new_node),
+                },
+            );
 
             // Update widths following C logic
             if i > 0 {
-                let width_before = update_width[i - 1] + {
-                    let upd_prev = update[i - 1].borrow();
-                    upd_prev.forward[i - 1].width
-                };
+                let width_before = update_width[i - 1] + self.node(update[i - 1]).forward[i - 1].width;
 
-                let new_node_width = if new_node.borrow().forward[i].width > 0 {
-                    new_node.borrow().forward[i].width + 1 - width_before
+                let new_node_width = if old_link.width > 0 {
+                    old_link.width + 1 - width_before
                 } else {
                     0
                 };
 
-                new_node.borrow_mut().forward[i].width = new_node_width;
-                update[i].borrow_mut().forward[i].width = width_before;
+                self.node_mut(new_node).forward[i] = Link {
+                    width: new_node_width,
+                    node: old_link.node,
+                };
+                self.node_mut(update[i]).forward[i].width = width_before;
             } else {
                 // Level 0
-                let old_width = new_node.borrow().forward[i].width;
-                new_node.borrow_mut().forward[i].width = old_width;
-                update[i].borrow_mut().forward[i].width = 1;
+                self.node_mut(new_node).forward[i] = old_link;
+                self.node_mut(update[i]).forward[i].width = 1;
             }
         }
 
         // Update widths of levels above the new node
-        for i in node_level..self.level as usize {
-            let mut upd = update[i].borrow_mut();
-            if upd.forward[i].node.is_some() {
-                upd.forward[i].width += 1;
+        for (i, &upd) in update.iter().enumerate().take(self.level as usize).skip(node_level) {
+            let upd = &mut self.nodes[upd as usize].forward[i];
+            if upd.node.is_some() {
+                upd.width += 1;
             } else {
                 break;
             }
         }
 
+        // Link the new node's successor (or the head, when it's the tail) back to it
+        let next = self.node(new_node).next().unwrap_or(HEAD);
+        self.node_mut(next).backward = new_node;
+
         self.width += 1;
-        None
+        new_node
     }
 
-    pub fn remove(&mut self, key: &K) -> Option<D> {
-        let mut update: Vec<Rc<RefCell<SkipNode<K, D>>>> = vec![self.head.clone(); self.level as usize];
-        let mut current = self.head.clone();
-
-        // Find the node to remove
-        for i in (0..self.level).rev() {
-            loop {
-                let next_node_rc = {
-                    let current_borrowed = current.borrow();
-                    current_borrowed.forward[i as usize].node.clone()
-                };
+    /// Links a node after the last entry without comparing keys, in `O(1)`
+    /// amortized. The caller guarantees `key` sorts after every key in the
+    /// list; `tail` and `position` come from `find_tail` and are kept current.
+    pub(crate) fn push_back_unchecked(
+        &mut self,
+        tail: &mut Vec<NodeId>,
+        position: &mut Vec<usize>,
+        key: K,
+        data: D,
+    ) {
+        self.grow_max_level(tail, position);
+        let node_level = self.random_level();
 
-                if let Some(next_rc) = next_node_rc {
-                    let next_node = next_rc.borrow();
-                    if let Some(next_key) = next_node.key.as_ref() {
-                        if (self.comparator)(next_key, key) == Ordering::Less {
-                            drop(next_node);
-                            current = next_rc;
-                            continue;
-                        }
-                    }
-                }
-                break;
+        if node_level > self.level as usize {
+            for i in self.level as usize..node_level {
+                tail[i] = HEAD;
+                position[i] = 0;
+                self.node_mut(HEAD).forward[i] = EMPTY_LINK;
             }
-            update[i as usize] = current.clone();
+            self.level = node_level as u16;
         }
 
-        // Get the node to remove
-        let target_node = {
-            let current_borrowed = current.borrow();
-            current_borrowed.forward[0].node.clone()
-        };
+        let new_node = self.alloc(key, data, node_level, tail[0]);
+        self.width += 1;
 
-        let target_node = match target_node {
-            Some(node) => node,
-            None => return None,
-        };
+        // Only the last node of each level changes; links past the tail stay null
+        for i in 0..node_level {
+            self.node_mut(tail[i]).forward[i] = Link {
+                width: self.width - position[i],
+                node: Some(new_node),
+            };
+            tail[i] = new_node;
+            position[i] = self.width;
+        }
 
-        // Verify it's the right node
-        let target_borrowed = target_node.borrow();
-        if let Some(target_key) = target_borrowed.key.as_ref() {
-            if (self.comparator)(target_key, key) != Ordering::Equal {
-                return None;
-            }
-        } else {
+        self.node_mut(HEAD).backward = new_node;
+    }
+
+    pub fn remove<Q: ?Sized>(&mut self, key: &Q) -> Option<D>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (update, _) = self.find_update(key);
+
+        // Get the node to remove and verify it's the right one
+        let target_node = self.node(update[0]).forward[0].node?;
+        if self.compare(target_node, key) != Ordering::Equal {
             return None;
         }
 
-        let old_data = target_borrowed.data.clone();
-        let target_forward = target_borrowed.forward.clone();
-        drop(target_borrowed);
+        let (_, data) = self.unlink(&update, target_node);
+        Some(data)
+    }
 
-        // Update the skip list structure
-        // Update the skip list structure
-        for i in 0..self.level as usize {
-            let mut upd = update[i].borrow_mut();
-            if let Some(upd_next) = upd.forward[i].node.as_ref() {
-                if Rc::ptr_eq(upd_next, &target_node) {
-                    // Save the original width before overwriting
-                    let original_width = upd.forward[i].width;
-                    
-                    // Update node pointer
-                    upd.forward[i].node = target_forward[i].node.clone();
-                    
-                    // Calculate new width using original width
-                    if target_forward[i].width > 0 {
-                        upd.forward[i].width = original_width + target_forward[i].width - 1;
-                    } else {
-                        upd.forward[i].width = 0;
-                    }
-                } else {
-                    if upd.forward[i].width > 0 {
-                        upd.forward[i].width -= 1;
-                    }
+    /// Removes the entry at `index`, returning its key and data.
+    pub fn remove_at(&mut self, index: usize) -> Option<(K, D)> {
+        if index >= self.width {
+            return None;
+        }
+        let (update, _) = self.find_update_at(index);
+        let target_node = self.node(update[0]).forward[0].node?;
+        Some(self.unlink(&update, target_node))
+    }
+
+    /// Removes and returns the entry with the smallest key. Every level of the
+    /// first node hangs directly off the head, so no descent is needed.
+    pub fn pop_first(&mut self) -> Option<(K, D)> {
+        let first = self.node(HEAD).next()?;
+        let update = vec![HEAD; self.max_level as usize];
+        Some(self.unlink(&update, first))
+    }
+
+    /// Removes and returns the entry with the largest key in `O(log n)`.
+    pub fn pop_last(&mut self) -> Option<(K, D)> {
+        self.remove_at(self.width.checked_sub(1)?)
+    }
+
+    /// Replaces the data at `index`, returning the previous data, or `None`
+    /// if `index` is out of bounds.
+    pub fn replace_at(&mut self, index: usize, data: D) -> Option<D> {
+        let id = self.node_at(index)?;
+        self.node_mut(id).data.replace(data)
+    }
+
+    /// Unlinks the `len` entries starting at `index` in `O(log n + len)` and
+    /// returns the first of them. Their slots are left for the caller to
+    /// `release`, following the level-0 links from the returned node.
+    pub(crate) fn detach_range(&mut self, index: usize, len: usize) -> Option<NodeId> {
+        if len == 0 {
+            return None;
+        }
+        let (update, position) = self.find_update_at(index);
+        let first = self.node(update[0]).next();
+        let end = index + len;
+
+        for (i, (&upd, &pos)) in update.iter().zip(&position).enumerate().take(self.level as usize) {
+            // Follow the level past the detached nodes, summing the widths skipped
+            let mut link = self.node(upd).forward[i];
+            let mut reached = pos + link.width;
+            while let Some(next) = link.node {
+                if reached > end {
+                    break;
                 }
+                link = self.node(next).forward[i];
+                reached += link.width;
             }
+            self.node_mut(upd).forward[i] = match link.node {
+                Some(_) => Link {
+                    width: reached - pos - len,
+                    node: link.node,
+                },
+                None => EMPTY_LINK,
+            };
         }
 
-        // Update level if necessary
+        let next = self.node(update[0]).next().unwrap_or(HEAD);
+        self.node_mut(next).backward = update[0];
+        self.width -= len;
+        self.shrink_level();
+        first
+    }
+
+    /// Drops empty levels from the top, never going below level 1. Under
+    /// `Growth::GrowAndShrink`, then drops the head levels the length no
+    /// longer calls for.
+    fn shrink_level(&mut self) {
         while self.level > 1 {
-            let head_borrowed = self.head.borrow();
-            if head_borrowed.forward[self.level as usize - 1].node.is_none() {
-                drop(head_borrowed);
+            if self.node(HEAD).forward[self.level as usize - 1].node.is_none() {
                 self.level -= 1;
             } else {
                 break;
             }
         }
+        // Two levels of slack keep an insert and a remove from resizing back and forth
+        while self.growth == Growth::GrowAndShrink
+            && self.max_level > MIN_MAX_LEVEL
+            && self.level < self.max_level
+            && (self.width as f64) < self.capacity(self.max_level - 3)
+        {
+            self.max_level -= 1;
+            self.nodes[HEAD as usize].forward.resize(self.max_level as usize);
+        }
+    }
+
+    /// Unlinks `target_node` given its predecessor on every level, and frees its slot.
+    pub(crate) fn unlink(&mut self, update: &[NodeId], target_node: NodeId) -> (K, D) {
+        // Update the skip list structure
+        for (i, &upd) in update.iter().enumerate().take(self.level as usize) {
+            let target_link = self.node(target_node).forward.get(i).copied();
+            let upd = &mut self.nodes[upd as usize].forward[i];
+            if upd.node.is_some() {
+                if upd.node == Some(target_node) {
+                    let target_link = target_link.expect("target node reaches this level");
+
+                    // Update node pointer
+                    upd.node = target_link.node;
+
+                    // Calculate new width using original width
+                    if target_link.width > 0 {
+                        upd.width = upd.width + target_link.width - 1;
+                    } else {
+                        upd.width = 0;
+                    }
+                } else if upd.width > 0 {
+                    upd.width -= 1;
+                }
+            }
+        }
+
+        let next = self.node(target_node).next().unwrap_or(HEAD);
+        self.node_mut(next).backward = self.node(target_node).backward;
 
         self.width -= 1;
-        old_data
+        self.shrink_level();
+        self.release(target_node)
+    }
+
+    /// Returns the index of `key` in `O(log n)`, the inverse of `key_at`. Like
+    /// `slice::binary_search`, a missing key gives `Err` with the index where
+    /// it would be inserted.
+    pub fn rank<Q: ?Sized>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (before, position) = self.last_before(key, false);
+        match self.node(before).next() {
+            Some(next) if self.compare(next, key) == Ordering::Equal => Ok(position),
+            _ => Err(position),
+        }
+    }
+
+    /// Entry with the smallest key, in `O(1)`.
+    pub fn first(&self) -> Option<(&K, &D)> {
+        self.node(self.node(HEAD).next()?).entry()
     }
 
-    fn node_at(&self, index: usize) -> Option<Rc<RefCell<SkipNode<K, D>>>> {
+    /// Entry with the largest key, in `O(1)` through the head's backward link.
+    pub fn last(&self) -> Option<(&K, &D)> {
+        self.node(self.node(HEAD).backward).entry()
+    }
+
+    /// First entry whose key is greater than or equal to `key`.
+    pub fn lower_bound<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &D)>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (before, _) = self.last_before(key, false);
+        self.node(self.node(before).next()?).entry()
+    }
+
+    /// First entry whose key is strictly greater than `key`.
+    pub fn upper_bound<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &D)>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (before, _) = self.last_before(key, true);
+        self.node(self.node(before).next()?).entry()
+    }
+
+    /// Last entry whose key is less than or equal to `key`.
+    pub fn floor<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &D)>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (before, _) = self.last_before(key, true);
+        self.node(before).entry()
+    }
+
+    /// First entry whose key is greater than or equal to `key`, same as `lower_bound`.
+    pub fn ceiling<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &D)>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        self.lower_bound(key)
+    }
+
+    /// Last entry whose key is strictly less than `key`.
+    pub fn predecessor<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &D)>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (before, _) = self.last_before(key, false);
+        self.node(before).entry()
+    }
+
+    /// First entry whose key is strictly greater than `key`, same as `upper_bound`.
+    pub fn successor<Q: ?Sized>(&self, key: &Q) -> Option<(&K, &D)>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        self.upper_bound(key)
+    }
+
+    pub(crate) fn node_at(&self, index: usize) -> Option<NodeId> {
         if index >= self.width {
             return None;
         }
 
         let mut remaining_width = index + 1; // +1 because of head node
-        let mut current = self.head.clone();
+        let mut current = HEAD;
 
         for i in (0..self.level as usize).rev() {
-            loop {
-                let current_borrowed = current.borrow();
-                let link = &current_borrowed.forward[i];
-                
-                if let Some(next_node) = link.node.as_ref() {
-                    if link.width <= remaining_width {
-                        remaining_width -= link.width;
-                        let next = next_node.clone();
-                        drop(current_borrowed);
-                        current = next;
-                        
-                        if remaining_width == 0 {
-                            return Some(current);
-                        }
-                    } else {
-                        break;
-                    }
-                } else {
+            while let Link { width, node: Some(next) } = self.node(current).forward[i] {
+                if width > remaining_width {
                     break;
                 }
+                remaining_width -= width;
+                current = next;
+
+                if remaining_width == 0 {
+                    return Some(current);
+                }
             }
         }
         None
     }
 
+    /// Moves every entry out into an owning iterator, leaving the list empty
+    /// but keeping its configuration.
+    pub(crate) fn take_all(&mut self) -> IntoIter<K, D> {
+        let head = SkipNode {
+            forward: Tower::new(self.max_level as usize),
+            backward: HEAD,
+            key: None,
+            data: None,
+        };
+        let nodes = std::mem::replace(&mut self.nodes, vec![head]);
+        let len = self.width;
+        self.free.clear();
+        self.level = 1;
+        self.width = 0;
+        self.shrink_level();
+        IntoIter::new(nodes, len)
+    }
+
+    /// Removes every entry, keeping the head and the arena's allocation.
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.free.clear();
+        self.nodes[HEAD as usize].forward = Tower::new(self.max_level as usize);
+        self.nodes[HEAD as usize].backward = HEAD;
+        self.level = 1;
+        self.width = 0;
+        self.shrink_level();
+    }
+
+    pub fn len(&self) -> usize {
+        self.width
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width == 0
+    }
+}
+
+impl<K: Clone, D: Clone, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    pub fn search<Q: ?Sized>(&self, key: &Q) -> Option<D>
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        self.get(key).cloned()
+    }
+
     pub fn key_at(&self, index: usize) -> Option<K> {
         self.node_at(index)
-            .and_then(|node| node.borrow().key.clone())
+            .and_then(|node| self.node(node).key.clone())
     }
 
     pub fn data_at(&self, index: usize) -> Option<D> {
         self.node_at(index)
-            .and_then(|node| node.borrow().data.clone())
-    }
-
-    pub fn display_list(&self, label_printer: Option<fn(&K, &D)>) {
-        for level in (0..self.level).rev() {
-            // Print widths
-            let mut current = self.head.clone();
-            loop {
-                let current_borrowed = current.borrow();
-                let link = &current_borrowed.forward[level as usize];
-                
-                if link.width > 0 {
-                    let width_str = link.width.to_string();
-                    let padding = link.width * 6;
-                    print!("{:^width$}", width_str, width = padding.saturating_sub(1));
-                }
-                
-                if let Some(next) = link.node.clone() {
-                    drop(current_borrowed);
-                    current = next;
-                } else {
-                    break;
-                }
+            .and_then(|node| self.node(node).data.clone())
+    }
+}
+
+impl<K, D, C: Comparator<K> + Clone, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Splits the list in two at `key`, returning the entries whose keys are
+    /// greater than or equal to it, like `BTreeMap::split_off`.
+    pub fn split_off<Q: ?Sized>(&mut self, key: &Q) -> Self
+    where
+        K: Borrow<Q>,
+        C: Comparator<Q>,
+    {
+        let (_, at) = self.last_before(key, false);
+        self.split_off_at(at)
+    }
+
+    /// Splits the list in two at `index`, keeping the first `index` entries and
+    /// returning the rest as a list with the same configuration.
+    ///
+    /// The frontier of links crossing the cut is found and rewired in
+    /// `O(log n)`, and the towers and widths on either side are kept as they
+    /// are. The nodes of the shorter side still have to move to a new arena,
+    /// which makes the split `O(log n + min(k, n - k))` for a suffix of `k`.
+    pub fn split_off_at(&mut self, index: usize) -> Self {
+        let mut other = SkipList::with_generator(
+            self.max_level,
+            self.p,
+            self.growth,
+            self.comparator.clone(),
+            self.fork_rng(),
+        );
+        if index >= self.width {
+            return other;
+        }
+
+        // Cut the one link crossing the split on every level, remembering where
+        // it led as the suffix head's link
+        let (update, position) = self.find_update_at(index);
+        let mut suffix_head = vec![EMPTY_LINK; self.level as usize];
+        for (i, link) in suffix_head.iter_mut().enumerate() {
+            let crossing = std::mem::replace(&mut self.node_mut(update[i]).forward[i], EMPTY_LINK);
+            if crossing.node.is_some() {
+                *link = Link {
+                    width: position[i] + crossing.width - index,
+                    node: crossing.node,
+                };
             }
-            println!();
-
-            // Print arrows
-            current = self.head.clone();
-            loop {
-                let current_borrowed = current.borrow();
-                let link = &current_borrowed.forward[level as usize];
-                
-                if link.width > 0 {
-                    let arrow_width = link.width * 6 - 3;
-                    print!("o{:->width$}> ", "", width = arrow_width);
-                } else {
-                    print!("x ");
-                }
+        }
 
-                if let Some(next) = link.node.clone() {
-                    drop(current_borrowed);
-                    current = next;
-                } else {
-                    break;
-                }
+        let prefix_len = index;
+        let suffix_len = self.width - index;
+        let prefix_last = update[0];
+        let suffix_first = suffix_head[0].node.expect("the suffix is not empty");
+        let suffix_last = self.node(HEAD).backward;
+        other.level = self.level;
+
+        if suffix_len <= prefix_len {
+            for (i, link) in suffix_head.into_iter().enumerate() {
+                other.nodes[HEAD as usize].forward[i] = link;
             }
-            println!(" Level {}", level);
-        }
-
-        // Print labels if provided
-        if let Some(printer) = label_printer {
-            let current_borrowed = self.head.borrow();
-            if let Some(first_node) = current_borrowed.forward[0].node.as_ref() {
-                //print!("      ");
-                let mut current = first_node.clone();
-                drop(current_borrowed);
-                
-                loop {
-                    let (key, data, next) = {
-                        let current_borrowed = current.borrow();
-                        (
-                            current_borrowed.key.clone(),
-                            current_borrowed.data.clone(),
-                            current_borrowed.forward[0].node.clone()
-                        )
-                    };
-                    
-                    if let (Some(key), Some(data)) = (key.as_ref(), data.as_ref()) {
-                        printer(key, data);
-                    }
-                    
-                    if let Some(next_node) = next {
-                        current = next_node;
-                    } else {
-                        break;
-                    }
-                }
-                println!();
+            self.move_run(suffix_first, suffix_len, &mut other.nodes);
+            self.node_mut(HEAD).backward = prefix_last;
+        } else {
+            // Move the prefix out instead, leave the suffix behind and swap
+            for (i, link) in suffix_head.into_iter().enumerate() {
+                other.nodes[HEAD as usize].forward[i] = self.node(HEAD).forward[i];
+                self.node_mut(HEAD).forward[i] = link;
             }
+            if let Some(prefix_first) = other.nodes[HEAD as usize].next() {
+                self.move_run(prefix_first, prefix_len, &mut other.nodes);
+            }
+            self.node_mut(HEAD).backward = suffix_last;
+            self.node_mut(suffix_first).backward = HEAD;
+            std::mem::swap(&mut self.nodes, &mut other.nodes);
+            std::mem::swap(&mut self.free, &mut other.free);
         }
-    }
 
-    pub fn len(&self) -> usize {
-        self.width
+        self.width = prefix_len;
+        other.width = suffix_len;
+        self.shrink_level();
+        other.shrink_level();
+        other
     }
 
-    // pub fn is_empty(&self) -> bool {
-    //     self.width == 0
-    // }
-}
\ No newline at end of file
+    /// Moves the run of `len` nodes starting at `first` to `into`, an arena
+    /// holding just a head whose links still name nodes of this arena, and
+    /// frees their slots. Links are renumbered on the way, so none of the
+    /// run's links may lead outside it.
+    fn move_run(&mut self, first: NodeId, len: usize, into: &mut Vec<SkipNode<K, D>>) {
+        debug_assert_eq!(into.len(), 1);
+
+        // Number the run in order, parking each new id in its backward link
+        let mut current = first;
+        for new_id in 1..=len {
+            let node = self.node_mut(current);
+            node.backward = new_id as NodeId;
+            current = node.next().unwrap_or(HEAD);
+        }
+
+        let renumber = |nodes: &[SkipNode<K, D>], forward: &mut Tower| {
+            for i in 0..forward.len {
+                if let Some(node) = forward[i].node {
+                    forward[i].node = Some(nodes[node as usize].backward);
+                }
+            }
+        };
+        renumber(&self.nodes, &mut into[HEAD as usize].forward);
+        into[HEAD as usize].backward = len as NodeId;
+        into.reserve(len);
+
+        // Links only lead forward, so every target is still in place when renumbered
+        let mut current = first;
+        for new_id in 1..=len {
+            let vacated = SkipNode {
+                forward: Tower::new(0),
+                backward: HEAD,
+                key: None,
+                data: None,
+            };
+            let mut node = std::mem::replace(&mut self.nodes[current as usize], vacated);
+            let next = node.next().unwrap_or(HEAD);
+            renumber(&self.nodes, &mut node.forward);
+            node.backward = new_id as NodeId - 1;
+            into.push(node);
+            self.free.push(current);
+            current = next;
+        }
+    }
+}
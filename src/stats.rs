@@ -0,0 +1,167 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::mem::size_of;
+
+use crate::comparator::Comparator;
+use crate::level::LevelGenerator;
+use crate::skiplist::{get_max_level, NodeId, SkipList, SkipNode, HEAD};
+
+/// Most entries `SkipList::stats` searches for to measure the search path.
+const SAMPLED_SEARCHES: usize = 1000;
+
+/// Shape and memory use of a list, returned by `SkipList::stats`. The
+/// `expected_*` fields are the averages for a list of the same length and
+/// probability, to compare against.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    /// Number of entries.
+    pub len: usize,
+    /// Levels in use, the height of the tallest tower.
+    pub level: usize,
+    /// Levels the list was configured with.
+    pub max_level: usize,
+    /// `get_max_level(len, p)`, the levels a list of this length calls for.
+    pub suggested_max_level: usize,
+    /// Nodes linked on each level in use, level 0 first.
+    pub nodes_per_level: Vec<usize>,
+    /// Nodes expected on each of those levels, `len * p^level`.
+    pub expected_per_level: Vec<f64>,
+    pub mean_height: f64,
+    /// `(1 - p^h) / (1 - p)`, `h` being the tallest tower `max_level` allows.
+    pub expected_mean_height: f64,
+    pub max_height: usize,
+    /// Links followed plus levels descended to reach an entry, averaged over
+    /// up to 1000 entries spread evenly along the list.
+    pub mean_hops: f64,
+    /// Pugh's bound on the search path, `log_{1/p}(len) / p + 1 / (1 - p)`.
+    pub expected_hops: f64,
+    /// Heap bytes of the node arena, free slots included.
+    pub node_bytes: usize,
+    /// Heap bytes of the tower links that do not fit in their node.
+    pub link_bytes: usize,
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Measures the shape of the list in `O(n)`, for tuning `p` and
+    /// `max_level`. Keys and data are not followed, so memory they own on
+    /// the heap is not counted.
+    pub fn stats(&self) -> Stats {
+        let p = self.probability() as f64;
+        let stride = self.width.div_ceil(SAMPLED_SEARCHES).max(1);
+        let mut nodes_per_level = vec![0; self.level()];
+        let mut samples: Vec<NodeId> = Vec::new();
+        let mut link_bytes = self.node(HEAD).spilled_bytes();
+        let (mut total_height, mut max_height) = (0, 0);
+
+        let (mut current, mut index) = (HEAD, 0);
+        while let Some(next) = self.node(current).next() {
+            let node = self.node(next);
+            for count in &mut nodes_per_level[..node.height()] {
+                *count += 1;
+            }
+            total_height += node.height();
+            max_height = max_height.max(node.height());
+            link_bytes += node.spilled_bytes();
+            if index % stride == 0 {
+                samples.push(next);
+            }
+            current = next;
+            index += 1;
+        }
+
+        // Towers stop one level short of `max_level`, as in the C version
+        let tallest = self.max_level().saturating_sub(1).max(1);
+        let expected_per_level = (0..self.level())
+            .map(|level| {
+                if level < tallest {
+                    self.width as f64 * p.powi(level as i32)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let suggested = get_max_level(self.width, self.probability())
+            .expect("the probability is checked when the list is created");
+        let hops: usize = samples.iter().map(|&id| self.search_path(id)).sum();
+        let expected_hops = if self.width == 0 {
+            0.0
+        } else {
+            (self.width as f64).ln() / (1.0 / p).ln() / p + 1.0 / (1.0 - p)
+        };
+
+        Stats {
+            len: self.width,
+            level: self.level(),
+            max_level: self.max_level(),
+            suggested_max_level: suggested as usize,
+            nodes_per_level,
+            expected_per_level,
+            mean_height: mean(total_height, self.width),
+            expected_mean_height: (1.0 - p.powi(tallest as i32)) / (1.0 - p),
+            max_height,
+            mean_hops: mean(hops, samples.len()),
+            expected_hops,
+            node_bytes: self.nodes.capacity() * size_of::<SkipNode<K, D>>(),
+            link_bytes,
+        }
+    }
+
+    /// Number of steps a search for the key of `target` takes from the top
+    /// of the head: every link followed, and every level descended, the last
+    /// descent being the step onto `target` itself.
+    fn search_path(&self, target: NodeId) -> usize {
+        let key = self.node(target).key.as_ref().expect("linked nodes hold a key");
+        let (mut current, mut hops) = (HEAD, 0);
+        for level in (0..self.level()).rev() {
+            while let Some(next) = self.node(current).link(level).node {
+                if self.compare(next, key) != Ordering::Less {
+                    break;
+                }
+                current = next;
+                hops += 1;
+            }
+            hops += 1;
+        }
+        hops
+    }
+}
+
+fn mean(total: usize, count: usize) -> f64 {
+    if count == 0 {
+        0.0
+    } else {
+        total as f64 / count as f64
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "length {}, level {} of {} (get_max_level suggests {})",
+            self.len, self.level, self.max_level, self.suggested_max_level
+        )?;
+        writeln!(
+            f,
+            "tower height: mean {:.2} (expected {:.2}), max {}",
+            self.mean_height, self.expected_mean_height, self.max_height
+        )?;
+        writeln!(
+            f,
+            "hops per search: {:.1} measured, {:.1} expected",
+            self.mean_hops, self.expected_hops
+        )?;
+        writeln!(
+            f,
+            "heap: {} bytes of nodes, {} bytes of spilled links",
+            self.node_bytes, self.link_bytes
+        )?;
+        write!(f, "level {:>10} {:>12}", "nodes", "expected")?;
+        for (level, (nodes, expected)) in
+            self.nodes_per_level.iter().zip(&self.expected_per_level).enumerate()
+        {
+            write!(f, "\n{:>5} {:>10} {:>12.1}", level, nodes, expected)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, IndexMut};
+
+use crate::comparator::Comparator;
+use crate::level::LevelGenerator;
+use crate::skiplist::SkipList;
+
+/// Collects into a list built with `Default`, so the comparator must need no state.
+impl<K, D, C: Comparator<K> + Default, G: LevelGenerator> FromIterator<(K, D)>
+    for SkipList<K, D, C, G>
+{
+    fn from_iter<I: IntoIterator<Item = (K, D)>>(iter: I) -> Self {
+        let mut list = SkipList::default();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Inserts every pair, later pairs replacing the data of equal keys.
+impl<K, D, C: Comparator<K>, G: LevelGenerator> Extend<(K, D)> for SkipList<K, D, C, G> {
+    fn extend<I: IntoIterator<Item = (K, D)>>(&mut self, iter: I) {
+        for (key, data) in iter {
+            self.insert(key, data);
+        }
+    }
+}
+
+impl<'a, K: Copy, D: Copy, C: Comparator<K>, G: LevelGenerator> Extend<(&'a K, &'a D)>
+    for SkipList<K, D, C, G>
+{
+    fn extend<I: IntoIterator<Item = (&'a K, &'a D)>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|(&key, &data)| (key, data)));
+    }
+}
+
+/// Positional access: `list[i]` is the data of the `i`-th smallest key.
+impl<K, D, C: Comparator<K>, G: LevelGenerator> Index<usize> for SkipList<K, D, C, G> {
+    type Output = D;
+
+    fn index(&self, index: usize) -> &D {
+        self.node_at(index)
+            .and_then(|node| self.node(node).data.as_ref())
+            .expect("index out of bounds")
+    }
+}
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> IndexMut<usize> for SkipList<K, D, C, G> {
+    fn index_mut(&mut self, index: usize) -> &mut D {
+        let node = self.node_at(index).expect("index out of bounds");
+        self.node_mut(node).data.as_mut().expect("linked nodes hold an entry")
+    }
+}
+
+impl<K: fmt::Debug, D: fmt::Debug, C, G> fmt::Debug for SkipList<K, D, C, G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Lists are equal when they hold equal entries in the same order, whatever
+/// the shape of their towers.
+impl<K: PartialEq, D: PartialEq, C, G> PartialEq for SkipList<K, D, C, G> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, D: Eq, C, G> Eq for SkipList<K, D, C, G> {}
+
+impl<K: PartialOrd, D: PartialOrd, C, G> PartialOrd for SkipList<K, D, C, G> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<K: Ord, D: Ord, C, G> Ord for SkipList<K, D, C, G> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
+impl<K: Hash, D: Hash, C, G> Hash for SkipList<K, D, C, G> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.width);
+        for entry in self.iter() {
+            entry.hash(state);
+        }
+    }
+}
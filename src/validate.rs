@@ -0,0 +1,103 @@
+use std::cmp::Ordering;
+
+use crate::comparator::Comparator;
+use crate::error::{ValidationError, Violation};
+use crate::level::LevelGenerator;
+use crate::skiplist::{SkipList, HEAD};
+
+impl<K, D, C: Comparator<K>, G: LevelGenerator> SkipList<K, D, C, G> {
+    /// Checks every structural invariant of the list in `O(n)`: keys
+    /// strictly increasing under the comparator, link widths equal to the
+    /// level-0 hops they span, backward links, the recorded length and level,
+    /// and that no arena slot is lost. Meant for tests and debugging.
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        let mut violations = Vec::new();
+
+        // Number the nodes along level 0. The step limit stops a cycle.
+        let mut position = vec![None; self.nodes.len()];
+        position[HEAD as usize] = Some(0);
+        let (mut previous, mut counted) = (HEAD, 0);
+        while let Some(next) = self.node(previous).next() {
+            if position[next as usize].is_some() || counted == self.nodes.len() {
+                break;
+            }
+            counted += 1;
+            position[next as usize] = Some(counted);
+            if let Some(previous_key) = self.node(previous).key.as_ref() {
+                if self.compare(next, previous_key) != Ordering::Greater {
+                    violations.push(Violation::OutOfOrder { index: counted - 1 });
+                }
+            }
+            if self.node(next).backward != previous {
+                violations.push(Violation::BrokenBackward { index: counted - 1 });
+            }
+            previous = next;
+        }
+        if self.node(HEAD).backward != previous {
+            violations.push(Violation::BrokenBackward { index: counted });
+        }
+        if counted != self.width {
+            violations.push(Violation::WrongLength {
+                recorded: self.width,
+                counted,
+            });
+        }
+
+        // Walk every level, comparing each width with the positions found above
+        let mut expected_level = 1;
+        for level in 0..self.max_level() {
+            let mut current = HEAD;
+            let mut steps = 0;
+            loop {
+                let link = self.node(current).link(level);
+                let from = position[current as usize].and_then(|at| at.checked_sub(1));
+                let found_at = match link.node {
+                    None => 0,
+                    Some(target) => match position[target as usize] {
+                        Some(at) if self.node(target).height() > level => at,
+                        _ => {
+                            violations.push(Violation::BadTarget { level, from });
+                            break;
+                        }
+                    },
+                };
+                let expected = found_at.saturating_sub(position[current as usize].unwrap_or(0));
+                if link.width != expected {
+                    violations.push(Violation::WrongWidth {
+                        level,
+                        from,
+                        expected,
+                        found: link.width,
+                    });
+                }
+                match link.node {
+                    Some(target) if steps < counted => {
+                        current = target;
+                        steps += 1;
+                    }
+                    _ => break,
+                }
+            }
+            if self.node(HEAD).link(level).node.is_some() {
+                expected_level = level + 1;
+            }
+        }
+        if self.level() != expected_level {
+            violations.push(Violation::WrongLevel {
+                recorded: self.level(),
+                expected: expected_level,
+            });
+        }
+
+        let leaked = (self.nodes.len() - 1).saturating_sub(counted + self.free_slots().len());
+        if leaked > 0 {
+            violations.push(Violation::LeakedSlots { count: leaked });
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationError { violations })
+        }
+    }
+}
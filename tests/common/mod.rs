@@ -0,0 +1,18 @@
+use skip_list_rust::SkipList;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+/// Checks the list against the model, including the widths through positional lookups.
+pub fn assert_matches<K: Ord + Copy + Debug, D: Clone + PartialEq + Debug>(
+    skip_list: &SkipList<K, D>,
+    model: &BTreeMap<K, D>,
+) {
+    skip_list.validate().unwrap();
+    assert_eq!(skip_list.len(), model.len());
+    assert!(skip_list.iter().eq(model.iter()));
+    assert!(skip_list.iter().rev().eq(model.iter().rev()));
+    for (i, key) in model.keys().enumerate() {
+        assert_eq!(skip_list.key_at(i), Some(*key));
+        assert_eq!(skip_list.rank(key), Ok(i));
+    }
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use common::assert_matches;
+use skip_list_rust::SkipList;
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+fn sample() -> (SkipList<i32, i32>, BTreeMap<i32, i32>) {
+    let model: BTreeMap<i32, i32> = (0..300).map(|i| (i * 2, i)).collect();
+    (model.iter().map(|(&key, &data)| (key, data)).collect(), model)
+}
+
+#[test]
+fn test_remove_and_drain_range() {
+    let (mut skip_list, mut model) = sample();
+    assert_eq!(skip_list.remove_range(100..=200), 51);
+    model.retain(|key, _| !(100..=200).contains(key));
+    assert_matches(&skip_list, &model);
+
+    let drained: Vec<_> = skip_list.drain_range(..20).collect();
+    assert_eq!(drained, (0..10).map(|i| (i * 2, i)).collect::<Vec<_>>());
+    model.retain(|key, _| *key >= 20);
+    assert_matches(&skip_list, &model);
+
+    // Dropping the iterator early still removes the whole range
+    let mut drain = skip_list.drain_range(500..);
+    assert_eq!(drain.len(), 50);
+    assert_eq!(drain.next(), Some((500, 250)));
+    drop(drain);
+    model.retain(|key, _| *key < 500);
+    assert_matches(&skip_list, &model);
+
+    assert_eq!(skip_list.remove_range(1000..2000), 0);
+    assert_eq!(skip_list.remove_range((Bound::Included(300), Bound::Excluded(250))), 0);
+    skip_list.insert(150, -1);
+    model.insert(150, -1);
+    assert_matches(&skip_list, &model);
+
+    assert_eq!(skip_list.remove_range(..), model.len());
+    assert!(skip_list.is_empty());
+    assert_eq!(skip_list.last(), None);
+}
+
+#[test]
+fn test_retain_and_extract_if() {
+    let (mut skip_list, mut model) = sample();
+    skip_list.retain(|key, data| {
+        *data += 1;
+        key % 3 != 0
+    });
+    model.retain(|key, data| {
+        *data += 1;
+        key % 3 != 0
+    });
+    assert_matches(&skip_list, &model);
+
+    let extracted: Vec<_> = skip_list.extract_if(|_, data| *data % 5 == 0).collect();
+    let expected: Vec<_> = model
+        .iter()
+        .filter(|(_, data)| *data % 5 == 0)
+        .map(|(&key, &data)| (key, data))
+        .collect();
+    assert_eq!(extracted, expected);
+    model.retain(|_, data| *data % 5 != 0);
+    assert_matches(&skip_list, &model);
+
+    // Stopping early keeps everything not visited yet
+    let first_two: Vec<_> = skip_list.extract_if(|key, _| *key > 100).take(2).collect();
+    for (key, _) in &first_two {
+        model.remove(key);
+    }
+    assert_eq!(first_two.len(), 2);
+    assert_matches(&skip_list, &model);
+
+    skip_list.retain(|_, _| false);
+    assert!(skip_list.is_empty());
+    skip_list.insert(1, 1);
+    assert_eq!(skip_list.first(), Some((&1, &1)));
+}
@@ -0,0 +1,56 @@
+use skip_list_rust::SkipList;
+
+/// Every node stays on level 0, so the output is fixed: a tower only grows
+/// on a draw below `p`, and the smallest positive `p` leaves just an exact 0.
+fn flat_list() -> SkipList<i32, &'static str> {
+    let mut skip_list = SkipList::builder().probability(f32::MIN_POSITIVE).build().unwrap();
+    skip_list.insert(2, "b|c");
+    skip_list.insert(1, "a \"quoted\"");
+    skip_list
+}
+
+#[test]
+fn test_to_dot() {
+    let dot = flat_list().to_dot(|key, data| format!("{}: {}", key, data));
+    assert_eq!(
+        dot,
+        "digraph skiplist {
+    rankdir=LR;
+    node [shape=record];
+    head [label=\"<l0> 0|head\"];
+    n0 [label=\"<l0> 0|1: a \\\"quoted\\\"\"];
+    n1 [label=\"<l0> 0|2: b\\|c\"];
+    head:l0 -> n0:l0 [label=\"1\"];
+    n0:l0 -> n1:l0 [label=\"1\"];
+}
+"
+    );
+
+    // Every link shows up once as an edge
+    let tall: SkipList<i32, i32> = (0..100).map(|i| (i, i)).collect();
+    let dot = tall.to_dot(|key, _| key.to_string());
+    let json = tall.to_json(|key, _| key.to_string());
+    let edges = dot.lines().filter(|line| line.contains(" -> ")).count();
+    let links = json.matches("\"to\":").count() - json.matches("\"to\":null").count();
+    assert_eq!(edges, links);
+    assert!(edges > 100);
+}
+
+#[test]
+fn test_to_json() {
+    let json = flat_list().to_json(|key, data| format!("{}: {}", key, data));
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"len":2,"level":1,"head":[{"width":1,"to":0}],"nodes":["#,
+            r#"{"label":"1: a \"quoted\"","links":[{"width":1,"to":1}]},"#,
+            r#"{"label":"2: b|c","links":[{"width":0,"to":null}]}]}"#
+        )
+    );
+
+    let empty: SkipList<i32, i32> = SkipList::default();
+    assert_eq!(
+        empty.to_json(|_, _| String::new()),
+        r#"{"len":0,"level":1,"head":[{"width":0,"to":null}],"nodes":[]}"#
+    );
+}
@@ -0,0 +1,72 @@
+use skip_list_rust::{OrdComparator, SkipList};
+use std::cmp::Ordering;
+
+fn int_comparator(a: &i32, b: &i32) -> Ordering {
+    a.cmp(b)
+}
+
+fn sample_list() -> SkipList<i32, String> {
+    let mut skip_list = SkipList::new(8, 0.5, OrdComparator);
+    for i in [5, 1, 9, 3, 7, 2, 8] {
+        skip_list.insert(i, format!("val_{}", i));
+    }
+    // Removing the first, last and a middle key exercises the backward links
+    skip_list.remove(&1);
+    skip_list.remove(&9);
+    skip_list.remove(&5);
+    skip_list
+}
+
+#[test]
+fn test_iter_forward_and_reverse() {
+    let skip_list = sample_list();
+
+    let keys: Vec<i32> = skip_list.keys().copied().collect();
+    assert_eq!(keys, vec![2, 3, 7, 8]);
+
+    let reversed: Vec<(i32, String)> = skip_list.iter().rev().map(|(k, d)| (*k, d.clone())).collect();
+    assert_eq!(reversed[0], (8, "val_8".to_string()));
+    assert_eq!(reversed[3], (2, "val_2".to_string()));
+
+    let values: Vec<&String> = skip_list.values().collect();
+    assert_eq!(values, ["val_2", "val_3", "val_7", "val_8"]);
+}
+
+#[test]
+fn test_iter_double_ended_meets_in_middle() {
+    let skip_list = sample_list();
+    let mut iter = skip_list.iter();
+
+    assert_eq!(iter.len(), 4);
+    assert_eq!(iter.next().map(|(k, _)| *k), Some(2));
+    assert_eq!(iter.next_back().map(|(k, _)| *k), Some(8));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.next_back().map(|(k, _)| *k), Some(7));
+    assert_eq!(iter.next().map(|(k, _)| *k), Some(3));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let empty: SkipList<i32, (), _> = SkipList::new(4, 0.5, int_comparator);
+    assert_eq!(empty.iter().next(), None);
+    assert_eq!(empty.iter().next_back(), None);
+}
+
+#[test]
+fn test_values_mut_and_into_iter() {
+    let mut skip_list = sample_list();
+
+    for value in skip_list.values_mut() {
+        value.push('!');
+    }
+    for (key, value) in &mut skip_list {
+        value.insert_str(0, &key.to_string());
+    }
+    assert_eq!(skip_list.get(&7).map(String::as_str), Some("7val_7!"));
+
+    let mut owned = skip_list.into_iter();
+    assert_eq!(owned.len(), 4);
+    assert_eq!(owned.next_back(), Some((8, "8val_8!".to_string())));
+    let rest: Vec<(i32, String)> = owned.collect();
+    assert_eq!(rest.len(), 3);
+    assert_eq!(rest[0], (2, "2val_2!".to_string()));
+}
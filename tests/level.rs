@@ -0,0 +1,49 @@
+use skip_list_rust::{LevelGenerator, SkipList, SplitMix64, XorShift64Star};
+use std::cmp::Ordering;
+
+fn int_comparator(a: &i32, b: &i32) -> Ordering {
+    a.cmp(b)
+}
+
+#[test]
+fn test_xorshift_matches_generate_data_py() {
+    // XorShift64Star(0xDEADBEEFCAFEBABE).next_u64() from generate_data.py
+    let mut rng = XorShift64Star::new(0xDEADBEEFCAFEBABE);
+    assert_eq!(rng.next_u64(), 12943654015325602036);
+    assert_eq!(rng.next_u64(), 16480895114099968628);
+    assert_eq!(rng.next_u64(), 5547084237848144802);
+}
+
+#[test]
+fn test_generators_are_seeded_and_in_range() {
+    let mut a = SplitMix64::from_seed(7);
+    let mut b = SplitMix64::from_seed(7);
+    let mut c = XorShift64Star::from_seed(0);
+    for _ in 0..1000 {
+        let x = a.next_f32();
+        assert_eq!(x, b.next_f32());
+        assert!((0.0..1.0).contains(&x));
+        assert!((0.0..1.0).contains(&c.next_f32()));
+    }
+}
+
+#[test]
+fn test_builder_with_custom_generator() {
+    let mut skip_list = SkipList::builder()
+        .comparator(int_comparator)
+        .max_level(8)
+        .probability(0.25)
+        .level_generator(SplitMix64::new(0))
+        .seed(99)
+        .build()
+        .unwrap();
+
+    for i in (0..200).rev() {
+        skip_list.insert(i, i * 2);
+    }
+    assert_eq!(skip_list.len(), 200);
+    for i in 0..200 {
+        assert_eq!(skip_list.key_at(i as usize), Some(i));
+        assert_eq!(skip_list.search(&i), Some(i * 2));
+    }
+}
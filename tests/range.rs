@@ -0,0 +1,100 @@
+use skip_list_rust::SkipList;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+fn int_comparator(a: &i32, b: &i32) -> Ordering {
+    a.cmp(b)
+}
+
+#[test]
+fn test_range_matches_btreemap() {
+    let mut skip_list = SkipList::new(10, 0.5, int_comparator);
+    let mut model = BTreeMap::new();
+    for i in 0..300 {
+        let key = (i * 37) % 300 * 2; // even keys 0..600 in scrambled order
+        skip_list.insert(key, i);
+        model.insert(key, i);
+    }
+
+    let bounds = [
+        Bound::Unbounded,
+        Bound::Included(-5),
+        Bound::Included(0),
+        Bound::Excluded(0),
+        Bound::Included(101),
+        Bound::Excluded(250),
+        Bound::Included(598),
+        Bound::Excluded(598),
+        Bound::Included(700),
+    ];
+    for &start in &bounds {
+        for &end in &bounds {
+            let valid = match (start, end) {
+                (Bound::Included(s), Bound::Included(e)) => s <= e,
+                (Bound::Included(s) | Bound::Excluded(s), Bound::Included(e) | Bound::Excluded(e)) => s < e,
+                _ => true,
+            };
+            if !valid {
+                // BTreeMap panics on inverted ranges, the skip list yields nothing
+                assert_eq!(skip_list.count_range((start, end)), 0);
+                assert_eq!(skip_list.range((start, end)).next(), None);
+                continue;
+            }
+            let expected: Vec<(&i32, &i32)> = model.range((start, end)).collect();
+            let range = skip_list.range((start, end));
+            assert_eq!(range.len(), expected.len());
+            assert_eq!(skip_list.count_range((start, end)), expected.len());
+            assert!(range.clone().eq(expected.iter().copied()));
+            assert!(range.rev().eq(expected.iter().rev().copied()));
+        }
+    }
+}
+
+#[test]
+fn test_range_mut() {
+    let mut skip_list = SkipList::new(8, 0.5, int_comparator);
+    for i in 0..20 {
+        skip_list.insert(i, i);
+    }
+
+    for (_, data) in skip_list.range_mut(5..10) {
+        *data *= 100;
+    }
+    let values: Vec<i32> = skip_list.range(3..=11).map(|(_, d)| *d).collect();
+    assert_eq!(values, vec![3, 4, 500, 600, 700, 800, 900, 10, 11]);
+    assert_eq!(skip_list.count_range(..), 20);
+    assert_eq!(skip_list.count_range(19..), 1);
+    assert_eq!(skip_list.count_range(20..), 0);
+}
+
+#[test]
+fn test_nearest_neighbour_lookups() {
+    let mut skip_list = SkipList::new(8, 0.5, int_comparator);
+    for key in [10, 20, 30, 40] {
+        skip_list.insert(key, key / 10);
+    }
+
+    assert_eq!(skip_list.lower_bound(&20), Some((&20, &2)));
+    assert_eq!(skip_list.lower_bound(&21), Some((&30, &3)));
+    assert_eq!(skip_list.lower_bound(&41), None);
+    assert_eq!(skip_list.upper_bound(&20), Some((&30, &3)));
+    assert_eq!(skip_list.upper_bound(&5), Some((&10, &1)));
+    assert_eq!(skip_list.upper_bound(&40), None);
+
+    assert_eq!(skip_list.floor(&25), Some((&20, &2)));
+    assert_eq!(skip_list.floor(&30), Some((&30, &3)));
+    assert_eq!(skip_list.floor(&9), None);
+    assert_eq!(skip_list.ceiling(&25), Some((&30, &3)));
+    assert_eq!(skip_list.ceiling(&10), Some((&10, &1)));
+
+    assert_eq!(skip_list.predecessor(&30), Some((&20, &2)));
+    assert_eq!(skip_list.predecessor(&10), None);
+    assert_eq!(skip_list.predecessor(&100), Some((&40, &4)));
+    assert_eq!(skip_list.successor(&30), Some((&40, &4)));
+    assert_eq!(skip_list.successor(&-1), Some((&10, &1)));
+
+    let empty: SkipList<i32, i32, _> = SkipList::new(4, 0.5, int_comparator);
+    assert_eq!(empty.floor(&0), None);
+    assert_eq!(empty.ceiling(&0), None);
+}
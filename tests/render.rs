@@ -0,0 +1,67 @@
+use skip_list_rust::{RenderOptions, SkipList};
+
+/// Keys 10 to 80 built with the default seed, so the towers are the same every
+/// run. `max_level(4)` lets them reach level 2, but this seed draws none that
+/// tall, which keeps the expected drawings to two levels.
+fn sample_list() -> SkipList<i32, i32> {
+    let mut skip_list = SkipList::builder().max_level(4).build().unwrap();
+    for i in 1..=8 {
+        skip_list.insert(i * 10, i);
+    }
+    skip_list
+}
+
+#[test]
+fn test_render() {
+    let skip_list = sample_list();
+    let mut out = String::new();
+    let options = RenderOptions::new().cell_width(4).label(|key: &i32, _: &i32| key.to_string());
+    skip_list.render(&mut out, &options).unwrap();
+    let expected = concat!(
+        " 1         4         1\n",
+        "o-> o-------------> o-> x  Level 1\n",
+        " 1   1   1   1   1   1   1   1\n",
+        "o-> o-> o-> o-> o-> o-> o-> o-> x  Level 0\n",
+        "  10  20  30  40  50  60  70  80\n",
+    );
+    assert_eq!(out, expected);
+
+    // The io sink gets the same text
+    let mut bytes = Vec::new();
+    skip_list.render_io(&mut bytes, &options).unwrap();
+    assert_eq!(String::from_utf8(bytes).unwrap(), expected);
+}
+
+#[test]
+fn test_render_elides() {
+    let skip_list = sample_list();
+    let mut out = String::new();
+    let options = RenderOptions::new().max_nodes(3).label(|key: &i32, _: &i32| key.to_string());
+    skip_list.render(&mut out, &options).unwrap();
+    assert_eq!(
+        out,
+        concat!(
+            "  1           4\n",
+            "o---> o-----------------...  Level 1\n",
+            "  1     1     1     1\n",
+            "o---> o---> o---> o-----...  Level 0\n",
+            "    10    20    30 ...\n",
+        )
+    );
+
+    let mut out = String::new();
+    skip_list.render(&mut out, &RenderOptions::new().max_levels(1).max_nodes(2)).unwrap();
+    assert_eq!(
+        out,
+        concat!(
+            "... 1 levels above\n",
+            "  1     1     1\n",
+            "o---> o---> o-----...  Level 0\n",
+        )
+    );
+
+    let empty: SkipList<i32, i32> = SkipList::default();
+    let mut out = String::new();
+    empty.render(&mut out, &RenderOptions::new()).unwrap();
+    assert_eq!(out, "\nx  Level 0\n");
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::assert_matches;
+use skip_list_rust::SkipList;
+use std::collections::BTreeMap;
+
+/// Pseudo-random keys in `0..range`, with the data recording which side they came from.
+fn random_entries(seed: u32, count: usize, range: u32, side: i32) -> Vec<(u32, i32)> {
+    let mut state = seed;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(1103515245).wrapping_add(12345);
+            ((state >> 8) % range, side)
+        })
+        .collect()
+}
+
+#[test]
+fn test_set_operations_match_btreemap() {
+    let left: SkipList<u32, i32> = random_entries(1, 700, 1000, 1).into_iter().collect();
+    let right: SkipList<u32, i32> = random_entries(2, 700, 1000, 2).into_iter().collect();
+    let left_model: BTreeMap<u32, i32> = left.iter().map(|(&key, &data)| (key, data)).collect();
+    let right_model: BTreeMap<u32, i32> = right.iter().map(|(&key, &data)| (key, data)).collect();
+
+    let union = left.clone().union(right.clone(), |_, ours, theirs| ours + theirs);
+    let mut model = right_model.clone();
+    for (key, data) in &left_model {
+        *model.entry(*key).or_insert(0) += data;
+    }
+    assert_matches(&union, &model);
+
+    let intersection = left.clone().intersection(right.clone(), |_, ours, theirs| ours * 10 + theirs);
+    let model = left_model
+        .iter()
+        .filter(|(key, _)| right_model.contains_key(key))
+        .map(|(&key, &data)| (key, data * 10 + 2))
+        .collect();
+    assert_matches(&intersection, &model);
+
+    let difference = left.clone().difference(right.clone());
+    let model = left_model
+        .iter()
+        .filter(|(key, _)| !right_model.contains_key(key))
+        .map(|(&key, &data)| (key, data))
+        .collect();
+    assert_matches(&difference, &model);
+
+    let symmetric = left.clone().symmetric_difference(right.clone());
+    let model = left_model
+        .iter()
+        .chain(right_model.iter())
+        .filter(|(key, _)| left_model.contains_key(key) != right_model.contains_key(key))
+        .map(|(&key, &data)| (key, data))
+        .collect();
+    assert_matches(&symmetric, &model);
+}
+
+#[test]
+fn test_append() {
+    let mut skip_list: SkipList<u32, i32> = [(1, 1), (3, 1), (5, 1)].into_iter().collect();
+    let mut other: SkipList<u32, i32> = [(2, 2), (3, 2), (9, 2)].into_iter().collect();
+    skip_list.append(&mut other);
+    assert!(other.is_empty());
+    assert_eq!(
+        skip_list.iter().collect::<Vec<_>>(),
+        vec![(&1, &1), (&2, &2), (&3, &2), (&5, &1), (&9, &2)]
+    );
+
+    // Both lists stay usable afterwards
+    other.insert(4, 2);
+    skip_list.append_with(&mut other, |_, ours, _| ours);
+    skip_list.insert(0, 0);
+    assert_eq!(skip_list.remove_at(3), Some((3, 2)));
+    assert_eq!(skip_list.keys().copied().collect::<Vec<_>>(), vec![0, 1, 2, 4, 5, 9]);
+    assert_eq!(skip_list.last(), Some((&9, &2)));
+}
@@ -0,0 +1,571 @@
+use skip_list_rust::{
+    get_max_level, BuildError, Comparator, ConfigError, Entry, Growth, OrderError, SkipList,
+    Violation,
+};
+use std::cmp::Ordering;
+use std::ops::Bound;
+
+fn int_comparator(a: &i32, b: &i32) -> Ordering {
+    a.cmp(b)
+}
+
+#[test]
+fn test_basic_operations() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+
+    // Test insertion and search
+    assert_eq!(skip_list.insert(5, "five".to_string()), None);
+    assert_eq!(skip_list.insert(3, "three".to_string()), None);
+    assert_eq!(skip_list.insert(7, "seven".to_string()), None);
+
+    assert_eq!(skip_list.search(&5), Some("five".to_string()));
+    assert_eq!(skip_list.search(&3), Some("three".to_string()));
+    assert_eq!(skip_list.search(&7), Some("seven".to_string()));
+    assert_eq!(skip_list.search(&1), None);
+
+    assert_eq!(skip_list.len(), 3);
+}
+
+#[test]
+fn test_update() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+
+    skip_list.insert(5, "five".to_string());
+    let old = skip_list.insert(5, "FIVE".to_string());
+
+    assert_eq!(old, Some("five".to_string()));
+    assert_eq!(skip_list.search(&5), Some("FIVE".to_string()));
+    assert_eq!(skip_list.len(), 1);
+}
+
+#[test]
+fn test_removal() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+
+    for i in [1, 3, 5, 7, 9] {
+        skip_list.insert(i, format!("val_{}", i));
+    }
+
+    assert_eq!(skip_list.remove(&5), Some("val_5".to_string()));
+    assert_eq!(skip_list.remove(&5), None);
+    assert_eq!(skip_list.search(&5), None);
+    assert_eq!(skip_list.len(), 4);
+
+    // Test removing first and last elements
+    assert_eq!(skip_list.remove(&1), Some("val_1".to_string()));
+    assert_eq!(skip_list.remove(&9), Some("val_9".to_string()));
+    assert_eq!(skip_list.len(), 2);
+}
+
+#[test]
+fn test_indexed_access() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+
+    let keys = [3, 1, 4, 1, 5, 9, 2, 6];
+    for (i, &key) in keys.iter().enumerate() {
+        skip_list.insert(key, i);
+    }
+
+    // Keys should be sorted: [1, 2, 3, 4, 5, 6, 9] (duplicate 1 updated)
+    let expected_keys = [1, 2, 3, 4, 5, 6, 9];
+
+    for (i, &expected_key) in expected_keys.iter().enumerate() {
+        assert_eq!(skip_list.key_at(i), Some(expected_key));
+    }
+
+    assert_eq!(skip_list.key_at(expected_keys.len()), None);
+}
+
+#[test]
+fn test_non_clone_payload() {
+    struct Payload(Vec<u8>);
+
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+    for i in [4, 2, 8, 6] {
+        assert!(skip_list.insert(i, Payload(vec![i as u8; 3])).is_none());
+    }
+
+    assert_eq!(skip_list.get(&2).map(|p| p.0.clone()), Some(vec![2, 2, 2]));
+    assert!(skip_list.get(&5).is_none());
+    assert!(skip_list.contains_key(&8));
+
+    skip_list.get_mut(&6).unwrap().0.push(7);
+    assert_eq!(skip_list.get(&6).unwrap().0, vec![6, 6, 6, 7]);
+    assert!(skip_list.get_mut(&7).is_none());
+
+    let removed = skip_list.remove(&4).unwrap();
+    assert_eq!(removed.0, vec![4, 4, 4]);
+    assert!(skip_list.remove(&4).is_none());
+    assert_eq!(skip_list.len(), 3);
+}
+
+#[test]
+fn test_matches_btreemap_after_churn() {
+    use std::collections::BTreeMap;
+
+    let mut skip_list = SkipList::new(12, 0.5, int_comparator);
+    let mut model = BTreeMap::new();
+
+    // Interleave inserts and removes so freed node slots get reused.
+    let mut state: u32 = 12345;
+    for _ in 0..5000 {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        let key = ((state >> 8) % 500) as i32;
+        if state.is_multiple_of(3) {
+            assert_eq!(skip_list.remove(&key), model.remove(&key));
+        } else {
+            assert_eq!(skip_list.insert(key, state), model.insert(key, state));
+        }
+    }
+
+    skip_list.validate().unwrap();
+    assert_eq!(skip_list.len(), model.len());
+    assert!(skip_list.iter().rev().eq(model.iter().rev()));
+    for (i, (key, data)) in model.iter().enumerate() {
+        assert_eq!(skip_list.key_at(i), Some(*key));
+        assert_eq!(skip_list.get(key), Some(data));
+    }
+}
+
+#[test]
+fn test_drop_and_clear_long_list_on_small_stack() {
+    let handle = std::thread::Builder::new()
+        .stack_size(64 * 1024)
+        .spawn(|| {
+            // Far more nodes than a recursive teardown could survive on 64 KiB
+            let n = 200_000;
+            let mut skip_list = SkipList::new(24, 0.5, int_comparator);
+            for i in 0..n {
+                skip_list.insert(i, ());
+            }
+            assert_eq!(skip_list.len(), n as usize);
+
+            // clear() tears the list down and leaves it reusable
+            skip_list.clear();
+            assert!(skip_list.is_empty());
+            assert_eq!(skip_list.key_at(0), None);
+            assert_eq!(skip_list.search(&0), None);
+
+            for i in 0..n {
+                skip_list.insert(i, ());
+            }
+            assert_eq!(skip_list.len(), n as usize);
+            drop(skip_list);
+        })
+        .unwrap();
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_rank() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+    assert_eq!(skip_list.rank(&5), Err(0));
+
+    for i in (0..100).map(|i| i * 10) {
+        skip_list.insert(i, ());
+    }
+    skip_list.remove(&500);
+
+    for index in 0..skip_list.len() {
+        let key = skip_list.key_at(index).unwrap();
+        assert_eq!(skip_list.rank(&key), Ok(index));
+        assert_eq!(skip_list.rank(&(key + 1)), Err(index + 1));
+    }
+    assert_eq!(skip_list.rank(&-1), Err(0));
+    assert_eq!(skip_list.rank(&500), Err(50));
+    assert_eq!(skip_list.rank(&10_000), Err(99));
+}
+
+#[test]
+fn test_positional_mutation() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+
+    assert_eq!(skip_list.insert_with_rank(50, "a"), (0, None));
+    assert_eq!(skip_list.insert_with_rank(10, "b"), (0, None));
+    assert_eq!(skip_list.insert_with_rank(30, "c"), (1, None));
+    assert_eq!(skip_list.insert_with_rank(70, "d"), (3, None));
+    assert_eq!(skip_list.insert_with_rank(30, "e"), (1, Some("c")));
+
+    assert_eq!(skip_list.replace_at(2, "f"), Some("a"));
+    assert_eq!(skip_list.replace_at(4, "g"), None);
+    assert_eq!(skip_list.get(&50), Some(&"f"));
+
+    assert_eq!(skip_list.remove_at(4), None);
+    assert_eq!(skip_list.remove_at(1), Some((30, "e")));
+    assert_eq!(skip_list.remove_at(2), Some((70, "d")));
+    assert_eq!(skip_list.remove_at(0), Some((10, "b")));
+    assert_eq!(skip_list.len(), 1);
+    assert_eq!(skip_list.key_at(0), Some(50));
+    assert_eq!(skip_list.iter().next_back(), Some((&50, &"f")));
+}
+
+#[test]
+fn test_remove_at_keeps_widths_consistent() {
+    let mut skip_list = SkipList::new(12, 0.5, int_comparator);
+    let mut keys: Vec<i32> = (0..1000).collect();
+    for &key in &keys {
+        skip_list.insert(key, ());
+    }
+
+    let mut state: u32 = 7;
+    while !keys.is_empty() {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        let index = (state >> 8) as usize % keys.len();
+        assert_eq!(skip_list.remove_at(index), Some((keys.remove(index), ())));
+        if keys.len().is_multiple_of(97) {
+            assert!(skip_list.keys().eq(keys.iter()));
+            for (i, key) in keys.iter().enumerate() {
+                assert_eq!(skip_list.rank(key), Ok(i));
+            }
+        }
+    }
+    assert!(skip_list.is_empty());
+}
+
+#[test]
+fn test_first_last_and_pop() {
+    let mut skip_list = SkipList::new(16, 0.5, int_comparator);
+    assert_eq!(skip_list.first(), None);
+    assert_eq!(skip_list.last(), None);
+    assert_eq!(skip_list.pop_first(), None);
+    assert_eq!(skip_list.pop_last(), None);
+
+    for i in [5, 2, 8, 1, 9, 3] {
+        skip_list.insert(i, i * 10);
+    }
+    assert_eq!(skip_list.first(), Some((&1, &10)));
+    assert_eq!(skip_list.last(), Some((&9, &90)));
+
+    assert_eq!(skip_list.pop_first(), Some((1, 10)));
+    assert_eq!(skip_list.pop_last(), Some((9, 90)));
+    assert_eq!(skip_list.pop_first(), Some((2, 20)));
+    assert_eq!(skip_list.first(), Some((&3, &30)));
+    assert_eq!(skip_list.last(), Some((&8, &80)));
+    assert_eq!(skip_list.len(), 3);
+    assert_eq!(skip_list.key_at(1), Some(5));
+
+    // Drain as a priority queue, with new work arriving in between
+    skip_list.insert(4, 40);
+    let mut order = Vec::new();
+    while let Some((key, _)) = skip_list.pop_first() {
+        order.push(key);
+        if key == 4 {
+            skip_list.insert(6, 60);
+        }
+    }
+    assert_eq!(order, vec![3, 4, 5, 6, 8]);
+    assert!(skip_list.is_empty());
+}
+
+#[test]
+fn test_entry() {
+    let mut counts = SkipList::new(16, 0.5, int_comparator);
+    for word in [3, 1, 3, 2, 3, 1] {
+        counts
+            .entry(word)
+            .and_modify(|count| *count += 1)
+            .or_insert(1);
+    }
+    assert_eq!(
+        counts.iter().collect::<Vec<_>>(),
+        vec![(&1, &2), (&2, &1), (&3, &3)]
+    );
+
+    *counts.entry(4).or_insert_with(|| 10) += 1;
+    *counts.entry(5).or_default() += 7;
+    assert_eq!(counts.get(&4), Some(&11));
+    assert_eq!(counts.get(&5), Some(&7));
+
+    match counts.entry(2) {
+        Entry::Occupied(mut entry) => {
+            assert_eq!(entry.insert(20), 1);
+            assert_eq!(entry.remove_entry(), (2, 20));
+        }
+        Entry::Vacant(_) => panic!("2 is in the list"),
+    }
+    match counts.entry(6) {
+        Entry::Vacant(entry) => assert_eq!(entry.into_key(), 6),
+        Entry::Occupied(_) => panic!("6 is not in the list"),
+    }
+    assert_eq!(counts.keys().copied().collect::<Vec<_>>(), vec![1, 3, 4, 5]);
+    assert_eq!(counts.rank(&4), Ok(2));
+    assert_eq!(counts.iter().rev().count(), 4);
+}
+
+#[test]
+fn test_comparators() {
+    // A closure capturing a flag
+    let descending = true;
+    let mut reversed = SkipList::new(16, 0.5, move |a: &i32, b: &i32| {
+        if descending { b.cmp(a) } else { a.cmp(b) }
+    });
+    for i in [5, 2, 8, 1] {
+        reversed.insert(i, ());
+    }
+    assert_eq!(reversed.keys().copied().collect::<Vec<_>>(), vec![8, 5, 2, 1]);
+    assert_eq!(reversed.lower_bound(&4), Some((&2, &())));
+
+    // Ordinary ordered keys need no comparator at all
+    let mut words: SkipList<String, usize> = SkipList::default();
+    for word in ["pear", "apple", "fig"] {
+        words.insert(word.to_string(), word.len());
+    }
+    assert_eq!(words.first(), Some((&"apple".to_string(), &5)));
+    assert_eq!(words.rank(&"fig".to_string()), Ok(1));
+
+    // Plain functions still work, as fn items or as fn pointers
+    let by_fn: SkipList<i32, (), fn(&i32, &i32) -> Ordering> = SkipList::new(4, 0.5, int_comparator);
+    assert!(by_fn.is_empty());
+}
+
+#[test]
+fn test_borrowed_lookups() {
+    let mut words: SkipList<String, usize> = SkipList::default();
+    for word in ["pear", "apple", "fig", "kiwi"] {
+        words.insert(word.to_string(), word.len());
+    }
+    assert_eq!(words.get("fig"), Some(&3));
+    assert!(words.contains_key("kiwi"));
+    assert_eq!(words.rank("kiwi"), Ok(2));
+    assert_eq!(words.lower_bound("b"), Some((&"fig".to_string(), &3)));
+    assert_eq!(words.search("apple"), Some(5));
+    let bounds = (Bound::Included("b"), Bound::Excluded("l"));
+    let in_range: Vec<_> = words.range::<str, _>(bounds).map(|(word, _)| word.as_str()).collect();
+    assert_eq!(in_range, vec!["fig", "kiwi"]);
+    assert_eq!(words.remove("pear"), Some(4));
+    assert_eq!(words.len(), 3);
+
+    // A comparator only has to order the borrowed form to be used with it
+    #[derive(Default)]
+    struct CaseInsensitive;
+    impl Comparator<str> for CaseInsensitive {
+        fn compare(&self, a: &str, b: &str) -> Ordering {
+            a.to_lowercase().cmp(&b.to_lowercase())
+        }
+    }
+    impl Comparator<String> for CaseInsensitive {
+        fn compare(&self, a: &String, b: &String) -> Ordering {
+            Comparator::<str>::compare(self, a, b)
+        }
+    }
+    let mut names: SkipList<String, (), CaseInsensitive> = SkipList::default();
+    names.insert("Bob".to_string(), ());
+    names.insert("alice".to_string(), ());
+    assert!(names.contains_key("BOB"));
+    assert_eq!(names.rank("Alice"), Ok(0));
+}
+
+#[test]
+fn test_from_sorted() {
+    let entries: Vec<(i32, i32)> = (0..5000).map(|i| (i * 2, i)).collect();
+    let mut bulk: SkipList<i32, i32> = SkipList::from_sorted_vec(entries.clone()).unwrap();
+    let inserted: SkipList<i32, i32> = entries.iter().copied().collect();
+    bulk.validate().unwrap();
+    assert_eq!(bulk, inserted);
+    assert_eq!(bulk.iter().rev().count(), 5000);
+
+    // The widths must be right for positional access and later updates
+    for i in (0..5000).step_by(97) {
+        assert_eq!(bulk.key_at(i as usize), Some(i * 2));
+        assert_eq!(bulk.rank(&(i * 2)), Ok(i as usize));
+    }
+    bulk.insert(3, -1);
+    assert_eq!(bulk.remove_at(0), Some((0, 0)));
+    assert_eq!(bulk.rank(&3), Ok(1));
+    assert_eq!(bulk.last(), Some((&9998, &4999)));
+    assert_eq!(bulk.count_range(100..200), 50);
+
+    let by_fn = SkipList::builder()
+        .comparator(int_comparator)
+        .max_level(8)
+        .build_from_sorted([(1, ()), (2, ())]);
+    assert_eq!(by_fn.unwrap().len(), 2);
+
+    let empty = SkipList::<i32, ()>::from_sorted_iter(std::iter::empty()).unwrap();
+    assert!(empty.is_empty());
+    let duplicate = SkipList::<i32, ()>::from_sorted_iter([(1, ()), (2, ()), (2, ())]);
+    assert_eq!(duplicate.unwrap_err(), OrderError::Duplicate { index: 2 });
+    let unsorted = SkipList::<i32, ()>::from_sorted_vec(vec![(1, ()), (3, ()), (2, ())]);
+    assert_eq!(unsorted.unwrap_err(), OrderError::OutOfOrder { index: 2 });
+}
+
+#[test]
+fn test_split_off() {
+    let keys: Vec<i32> = (0..80).map(|i| i * 3).collect();
+    // Every cut point, so both the prefix and the suffix get moved
+    for index in 0..=keys.len() + 1 {
+        let mut prefix: SkipList<i32, i32> = keys.iter().map(|&key| (key, -key)).collect();
+        prefix.remove(&30);
+        prefix.insert(30, -30);
+        let mut suffix = prefix.split_off_at(index);
+
+        let cut = index.min(keys.len());
+        for (skip_list, expected) in [(&mut prefix, &keys[..cut]), (&mut suffix, &keys[cut..])] {
+            skip_list.validate().unwrap();
+            assert_eq!(skip_list.len(), expected.len());
+            assert!(skip_list.keys().eq(expected.iter()));
+            assert!(skip_list.keys().rev().eq(expected.iter().rev()));
+            for (i, key) in expected.iter().enumerate() {
+                assert_eq!(skip_list.key_at(i), Some(*key));
+                assert_eq!(skip_list.rank(key), Ok(i));
+            }
+            // Both halves keep working as independent lists
+            skip_list.insert(1000, 0);
+            skip_list.insert(-1, 0);
+            assert_eq!(skip_list.rank(&1000), Ok(expected.len() + 1));
+            assert_eq!(skip_list.pop_first(), Some((-1, 0)));
+            assert_eq!(skip_list.pop_last(), Some((1000, 0)));
+        }
+    }
+
+    let mut skip_list: SkipList<i32, i32> = keys.iter().map(|&key| (key, key)).collect();
+    let tail = skip_list.split_off(&100);
+    assert_eq!(skip_list.last(), Some((&99, &99)));
+    assert_eq!(tail.first(), Some((&102, &102)));
+    assert_eq!(skip_list.len() + tail.len(), keys.len());
+
+    // The split-off list draws its own tower heights
+    let mut other = skip_list.split_off_at(skip_list.len());
+    skip_list.clear();
+    for i in 0..500 {
+        skip_list.insert(i, i);
+        other.insert(i, i);
+    }
+    assert_ne!(skip_list.to_json(|_, _| String::new()), other.to_json(|_, _| String::new()));
+}
+
+#[test]
+fn test_validate_reports_broken_order() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let reversed = Rc::new(Cell::new(false));
+    let flag = reversed.clone();
+    let mut skip_list = SkipList::new(8, 0.5, move |a: &i32, b: &i32| {
+        if flag.get() { b.cmp(a) } else { a.cmp(b) }
+    });
+    for i in 0..50 {
+        skip_list.insert(i, ());
+    }
+    for i in (0..50).step_by(3) {
+        skip_list.remove(&i);
+    }
+    assert_eq!(skip_list.validate(), Ok(()));
+
+    // Changing the order under the list's feet breaks every adjacent pair
+    reversed.set(true);
+    let error = skip_list.validate().unwrap_err();
+    assert_eq!(error.violations.len(), skip_list.len() - 1);
+    assert_eq!(error.violations[0], Violation::OutOfOrder { index: 1 });
+    assert!(error.to_string().starts_with("skip list has 32 broken invariants\n"));
+}
+
+#[test]
+fn test_growth() {
+    // A fixed cap stops the towers short, a growing one keeps up with the length
+    let mut fixed: SkipList<u32, ()> = SkipList::builder().max_level(4).build().unwrap();
+    let mut grown: SkipList<u32, ()> = SkipList::with_expected_len(16);
+    assert_eq!(grown.stats().max_level, 5);
+    for i in 0..20_000u32 {
+        fixed.insert(i.wrapping_mul(2654435761), ());
+        grown.insert(i.wrapping_mul(2654435761), ());
+    }
+    fixed.validate().unwrap();
+    grown.validate().unwrap();
+    assert_eq!(fixed.stats().max_level, 4);
+    let stats = grown.stats();
+    assert!((stats.max_level - 1) as f64 >= (20_000f64).log2());
+    assert!(stats.level > 12);
+    assert!(stats.mean_hops < stats.expected_hops * 1.25);
+    assert!(fixed.stats().mean_hops > stats.mean_hops * 10.0);
+
+    // Growing while loading sorted input or through entries
+    let sorted = SkipList::builder()
+        .max_level(3)
+        .growth(Growth::Grow)
+        .build_from_sorted((0..5000).map(|i| (i, i)))
+        .unwrap();
+    sorted.validate().unwrap();
+    assert!(sorted.stats().max_level > 12);
+    let mut entries: SkipList<i32, i32> =
+        SkipList::builder().max_level(3).growth(Growth::Grow).build().unwrap();
+    for i in 0..1000 {
+        *entries.entry(i % 700).or_default() += 1;
+    }
+    entries.validate().unwrap();
+    assert_eq!(entries.len(), 700);
+    assert!(entries.stats().max_level > 9);
+
+    // Shrinking follows the list back down
+    let mut skip_list: SkipList<i32, i32> =
+        SkipList::builder().max_level(3).growth(Growth::GrowAndShrink).build().unwrap();
+    for i in 0..10_000 {
+        skip_list.insert(i, i);
+    }
+    let grown_to = skip_list.stats().max_level;
+    skip_list.retain(|key, _| key % 1000 == 0);
+    skip_list.validate().unwrap();
+    assert!(skip_list.stats().max_level < grown_to);
+    assert!(skip_list.stats().max_level <= 6);
+    skip_list.remove_range(0..5000);
+    skip_list.validate().unwrap();
+    assert_eq!(skip_list.keys().copied().collect::<Vec<_>>(), vec![5000, 6000, 7000, 8000, 9000]);
+    for i in 0..100 {
+        skip_list.insert(i, i);
+    }
+    skip_list.validate().unwrap();
+    skip_list.clear();
+    assert_eq!(skip_list.stats().max_level, 3);
+    skip_list.insert(1, 1);
+    skip_list.validate().unwrap();
+}
+
+#[test]
+fn test_builder_rejects_bad_parameters() {
+    for p in [0.0, 1.0, -0.5, 1.5, f32::NAN] {
+        let error = SkipList::<i32, ()>::builder().probability(p).build().unwrap_err();
+        assert!(matches!(error, ConfigError::InvalidProbability { .. }));
+    }
+    for max_level in [0, 1, 2] {
+        let error = SkipList::<i32, ()>::builder().max_level(max_level).build().unwrap_err();
+        assert_eq!(error, ConfigError::InvalidMaxLevel { max_level });
+    }
+    let error = SkipList::<i32, ()>::builder().probability(1.0).build().unwrap_err();
+    assert_eq!(error.to_string(), "probability must be strictly between 0 and 1, got 1");
+    let error = SkipList::<i32, ()>::builder().max_level(2).build().unwrap_err();
+    assert_eq!(error.to_string(), "max_level must be at least 3, got 2");
+
+    // The smallest accepted max_level still lets towers rise above level 0
+    let mut skip_list = SkipList::builder()
+        .comparator(|a: &i32, b: &i32| b.cmp(a))
+        .max_level(3)
+        .seed(7)
+        .build()
+        .unwrap();
+    for i in 0..1000 {
+        skip_list.insert(i, i);
+    }
+    skip_list.validate().unwrap();
+    assert_eq!(skip_list.first(), Some((&999, &999)));
+    let stats = skip_list.stats();
+    assert_eq!(stats.level, 2);
+    assert!(stats.mean_hops < 500.0);
+
+    // `new` and `get_max_level` reject the same parameters
+    assert_eq!(get_max_level(1000, 2.0), Err(ConfigError::InvalidProbability { p: 2.0 }));
+    assert!(get_max_level(1000, 1.0).is_err());
+    assert_eq!(get_max_level(1, 0.5), Ok(3));
+    assert_eq!(get_max_level(1 << 20, 0.5), Ok(20));
+    for (max_level, p) in [(0, 0.5), (2, 0.5), (8, 0.0), (8, 2.0)] {
+        let new = || SkipList::<i32, (), _>::new(max_level, p, int_comparator);
+        assert!(std::panic::catch_unwind(new).is_err());
+    }
+
+    let sorted = SkipList::builder().max_level(0).build_from_sorted([(1, 1)]);
+    assert_eq!(
+        sorted.unwrap_err(),
+        BuildError::Config(ConfigError::InvalidMaxLevel { max_level: 0 })
+    );
+    let sorted = SkipList::builder().build_from_sorted([(2, 2), (1, 1)]);
+    assert_eq!(sorted.unwrap_err(), BuildError::Order(OrderError::OutOfOrder { index: 1 }));
+}
@@ -0,0 +1,41 @@
+use skip_list_rust::{get_max_level, SkipList};
+
+#[test]
+fn test_stats() {
+    let mut skip_list: SkipList<u32, u32> = SkipList::builder().max_level(20).build().unwrap();
+    for i in 0..20_000u32 {
+        skip_list.insert(i.wrapping_mul(2654435761), i);
+    }
+    let stats = skip_list.stats();
+    assert_eq!(stats.len, 20_000);
+    assert_eq!(stats.max_level, 20);
+    assert_eq!(stats.suggested_max_level, get_max_level(20_000, 0.5).unwrap() as usize);
+    assert_eq!(stats.nodes_per_level.len(), stats.level);
+    assert_eq!(stats.expected_per_level.len(), stats.level);
+    assert_eq!(stats.nodes_per_level[0], 20_000);
+    assert!(stats.nodes_per_level.windows(2).all(|pair| pair[0] >= pair[1]));
+    assert_eq!(stats.max_height, stats.level);
+
+    // Every node is counted once per level it is linked on
+    let total: usize = stats.nodes_per_level.iter().sum();
+    assert!((stats.mean_height - total as f64 / 20_000.0).abs() < 1e-9);
+    assert!((stats.mean_height - stats.expected_mean_height).abs() < 0.1);
+    for (&nodes, &expected) in stats.nodes_per_level.iter().zip(&stats.expected_per_level).take(5) {
+        assert!((nodes as f64 - expected).abs() < expected * 0.1);
+    }
+    assert!(stats.mean_hops > 0.0 && stats.mean_hops < stats.expected_hops * 1.25);
+    assert!(stats.node_bytes > 0);
+
+    // Removing entries leaves free slots in the arena, which still count
+    let node_bytes = stats.node_bytes;
+    skip_list.retain(|key, _| key % 2 == 0);
+    let stats = skip_list.stats();
+    assert_eq!(stats.nodes_per_level[0], skip_list.len());
+    assert_eq!(stats.node_bytes, node_bytes);
+
+    let empty: SkipList<u32, u32> = SkipList::default();
+    let stats = empty.stats();
+    assert_eq!((stats.len, stats.max_height, stats.mean_hops), (0, 0, 0.0));
+    assert_eq!(stats.nodes_per_level, vec![0]);
+    assert!(stats.to_string().starts_with("length 0, level 1 of 32"));
+}
@@ -0,0 +1,60 @@
+use skip_list_rust::SkipList;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_collect_extend_and_index() {
+    let mut skip_list: SkipList<i32, &str> = [(3, "c"), (1, "a"), (2, "b")].into_iter().collect();
+    assert_eq!(skip_list.len(), 3);
+    assert_eq!(skip_list[0], "a");
+    assert_eq!(skip_list[2], "c");
+
+    skip_list.extend([(0, "z"), (2, "B")]);
+    skip_list.extend(&[(5, "e")].into_iter().collect::<BTreeMap<_, _>>());
+    assert_eq!(skip_list.len(), 5);
+    assert_eq!(skip_list[2], "B");
+    assert_eq!(skip_list[4], "e");
+
+    skip_list[1] = "A";
+    assert_eq!(skip_list.get(&1), Some(&"A"));
+    assert_eq!(format!("{:?}", skip_list), r#"{0: "z", 1: "A", 2: "B", 3: "c", 5: "e"}"#);
+}
+
+#[test]
+#[should_panic(expected = "index out of bounds")]
+fn test_index_out_of_bounds() {
+    let skip_list: SkipList<i32, i32> = (0..3).map(|i| (i, i)).collect();
+    let _ = skip_list[3];
+}
+
+#[test]
+fn test_clone_eq_and_hash() {
+    let original: SkipList<i32, i32> = (0..200).map(|i| (i, i * i)).collect();
+    let mut copy = original.clone();
+    assert_eq!(copy, original);
+    assert_eq!(hash_of(&copy), hash_of(&original));
+
+    // The clone owns its nodes, changing it leaves the original alone
+    copy.remove(&10);
+    copy.insert(500, 0);
+    *copy.get_mut(&20).unwrap() = -1;
+    assert_eq!(original.len(), 200);
+    assert_eq!(original.get(&10), Some(&100));
+    assert_eq!(original.get(&20), Some(&400));
+    assert_eq!(copy.rank(&500), Ok(199));
+    assert_eq!(copy.iter().rev().count(), 200);
+    assert_ne!(copy, original);
+    assert!(copy > original);
+
+    // Equal entries compare equal even when built in a different order
+    let reordered: SkipList<i32, i32> = (0..200).rev().map(|i| (i, i * i)).collect();
+    assert_eq!(reordered, original);
+    assert_eq!(hash_of(&reordered), hash_of(&original));
+}